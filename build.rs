@@ -161,6 +161,8 @@ fn main() {
         WChar::Unknown => None,
     };
 
+    println!("cargo:rustc-check-cfg=cfg(wchar_t, values(\"u16\", \"u32\", \"i32\"))");
+
     match wchar_t {
         Some(wchar_t) => println!("cargo:rustc-cfg=wchar_t=\"{}\"", wchar_t),
         None => println!("cargo:warning=unknown platform wchar_t"),
@@ -0,0 +1,83 @@
+//! The encoding of strings as wide characters, shared with `wchar-impl` so that
+//! generated strings are identical to those of the `wch` and `wchz` macros.
+
+use std::fmt;
+
+pub trait Unit: Copy + fmt::Display {
+    // The width of a code unit in bits.
+    const BITS: u32;
+
+    // Converts a code unit, which must fit within `BITS` bits.
+    fn from_unit(unit: u32) -> Self;
+}
+
+impl Unit for u16 {
+    const BITS: u32 = 16;
+
+    fn from_unit(unit: u32) -> Self {
+        unit as u16
+    }
+}
+
+impl Unit for u32 {
+    const BITS: u32 = 32;
+
+    fn from_unit(unit: u32) -> Self {
+        unit
+    }
+}
+
+impl Unit for i16 {
+    const BITS: u32 = 16;
+
+    fn from_unit(unit: u32) -> Self {
+        unit as u16 as i16
+    }
+}
+
+impl Unit for i32 {
+    const BITS: u32 = 32;
+
+    fn from_unit(unit: u32) -> Self {
+        unit as i32
+    }
+}
+
+// Encodes a character as a single code unit, or `None` if it needs a
+// surrogate pair.
+pub fn encode_char<T: Unit>(c: char) -> Option<T> {
+    if T::BITS == 16 && c.len_utf16() != 1 {
+        None
+    } else {
+        Some(T::from_unit(c as u32))
+    }
+}
+
+pub fn encode_str<T: Unit>(s: &str) -> Vec<T> {
+    let mut units = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        push_char(&mut units, c);
+    }
+    units
+}
+
+pub fn encode_str_c<T: Unit>(s: &str) -> Vec<T> {
+    let mut units = encode_str(s);
+    units.push(T::from_unit(0));
+    units
+}
+
+// Appends the code units of a character, as UTF-16 or UTF-32 depending on the
+// width of the unit.
+pub fn push_char<T: Unit>(units: &mut Vec<T>, c: char) {
+    if T::BITS == 16 {
+        let mut buf = [0; 2];
+        units.extend(
+            c.encode_utf16(&mut buf)
+                .iter()
+                .map(|&unit| T::from_unit(unit as u32)),
+        );
+    } else {
+        units.push(T::from_unit(c as u32));
+    }
+}
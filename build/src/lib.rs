@@ -0,0 +1,260 @@
+//! This library generates UTF-16 or UTF-32 wide string constants from a build
+//! script, for strings that are only known at build time.
+//!
+//! The constants are written to a Rust source file, usually in `OUT_DIR`, and
+//! are encoded exactly as by the [`wch`] and [`wchz`] macros of the `wchar`
+//! crate.
+//!
+//! [`wch`]: https://docs.rs/wchar/latest/wchar/macro.wch.html
+//! [`wchz`]: https://docs.rs/wchar/latest/wchar/macro.wchz.html
+//!
+//! # Example
+//!
+//! In `build.rs`:
+//!
+//! ```no_run
+//! use wchar_build::{WCharType, WideStrings};
+//!
+//! WideStrings::new()
+//!     .add("GREETING", "Hello, world!", WCharType::U16, false)
+//!     .add("CLASS_NAME", "MainWindow", WCharType::U16, true)
+//!     .write_to_out_dir("strings.rs")
+//!     .unwrap();
+//! ```
+//!
+//! Then in the crate:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/strings.rs"));
+//!
+//! assert_eq!(GREETING, wchar::wch!(u16, "Hello, world!"));
+//! assert_eq!(CLASS_NAME, wchar::wchz!(u16, "MainWindow"));
+//! ```
+
+use std::collections::HashSet;
+use std::error;
+use std::fmt::{self, Write};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[doc(hidden)]
+pub mod encode;
+
+use crate::encode::Unit;
+
+/// The character type of a wide string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WCharType {
+    /// UTF-16 encoded `u16` characters.
+    U16,
+    /// UTF-32 encoded `u32` characters.
+    U32,
+    /// UTF-16 encoded `i16` characters.
+    I16,
+    /// UTF-32 encoded `i32` characters.
+    I32,
+}
+
+impl WCharType {
+    /// Returns the name of the Rust type of the characters.
+    pub fn name(self) -> &'static str {
+        match self {
+            WCharType::U16 => "u16",
+            WCharType::U32 => "u32",
+            WCharType::I16 => "i16",
+            WCharType::I32 => "i32",
+        }
+    }
+}
+
+struct Entry {
+    name: String,
+    text: String,
+    ty: WCharType,
+    nul_terminated: bool,
+}
+
+/// A set of wide string constants to generate.
+///
+/// Each constant is generated as `pub const NAME: &[T] = &[...];`, in the
+/// order they are added.
+#[derive(Default)]
+pub struct WideStrings {
+    entries: Vec<Entry>,
+}
+
+impl WideStrings {
+    /// Creates an empty set of constants.
+    pub fn new() -> Self {
+        WideStrings::default()
+    }
+
+    /// Adds a constant with the given name, text and character type.
+    ///
+    /// If `nul_terminated` is `true` the constant is a C-style string, as
+    /// generated by `wchz`, otherwise it is as generated by `wch`.
+    ///
+    /// Names must be valid Rust identifiers and unique, and C-style strings
+    /// cannot contain nul characters, which is checked when the constants are
+    /// generated.
+    pub fn add(
+        &mut self,
+        name: impl Into<String>,
+        text: impl Into<String>,
+        ty: WCharType,
+        nul_terminated: bool,
+    ) -> &mut Self {
+        self.entries.push(Entry {
+            name: name.into(),
+            text: text.into(),
+            ty,
+            nul_terminated,
+        });
+        self
+    }
+
+    /// Generates the Rust source of the constants.
+    pub fn generate(&self) -> Result<String, Error> {
+        let mut names = HashSet::new();
+        let mut source = String::from("// Generated by wchar-build, do not edit.\n");
+
+        for entry in &self.entries {
+            if !is_ident(&entry.name) {
+                return Err(Error::new(ErrorKind::InvalidName(entry.name.clone())));
+            }
+            if !names.insert(entry.name.as_str()) {
+                return Err(Error::new(ErrorKind::DuplicateName(entry.name.clone())));
+            }
+            if entry.nul_terminated && entry.text.contains('\0') {
+                return Err(Error::new(ErrorKind::Nul(entry.name.clone())));
+            }
+
+            match entry.ty {
+                WCharType::U16 => write_entry::<u16>(&mut source, entry),
+                WCharType::U32 => write_entry::<u32>(&mut source, entry),
+                WCharType::I16 => write_entry::<i16>(&mut source, entry),
+                WCharType::I32 => write_entry::<i32>(&mut source, entry),
+            }
+        }
+
+        Ok(source)
+    }
+
+    /// Generates the constants and writes them to a file.
+    ///
+    /// The file is left untouched if its contents are unchanged, so that
+    /// crates including it are not rebuilt needlessly.
+    pub fn write_to(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let source = self.generate()?;
+
+        if fs::read_to_string(path).ok().as_ref() == Some(&source) {
+            return Ok(());
+        }
+
+        fs::write(path, source).map_err(|err| Error::new(ErrorKind::Io(path.to_owned(), err)))
+    }
+
+    /// Generates the constants and writes them to a file in `OUT_DIR`,
+    /// returning the path of the file.
+    ///
+    /// This must be called from a build script, where Cargo sets `OUT_DIR`.
+    pub fn write_to_out_dir(&self, file_name: impl AsRef<Path>) -> Result<PathBuf, Error> {
+        let out_dir = std::env::var_os("OUT_DIR").ok_or_else(|| Error::new(ErrorKind::OutDir))?;
+        let path = Path::new(&out_dir).join(file_name);
+
+        self.write_to(&path)?;
+
+        Ok(path)
+    }
+}
+
+fn write_entry<T: Unit>(source: &mut String, entry: &Entry) {
+    let units: Vec<T> = if entry.nul_terminated {
+        encode::encode_str_c(&entry.text)
+    } else {
+        encode::encode_str(&entry.text)
+    };
+
+    write!(
+        source,
+        "\npub const {}: &[{}] = &[",
+        entry.name,
+        entry.ty.name()
+    )
+    .unwrap();
+    for (i, unit) in units.iter().enumerate() {
+        if i > 0 {
+            source.push_str(", ");
+        }
+        write!(source, "{}", unit).unwrap();
+    }
+    source.push_str("];\n");
+}
+
+// Checks that a name is an ASCII identifier, and not a keyword.
+fn is_ident(name: &str) -> bool {
+    const KEYWORDS: &[&str] = &[
+        "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+        "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if",
+        "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv",
+        "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "try",
+        "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield", "_",
+    ];
+
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(c) if c == '_' || c.is_ascii_alphabetic() => {}
+        _ => return false,
+    }
+
+    chars.all(|c| c == '_' || c.is_ascii_alphanumeric()) && !KEYWORDS.contains(&name)
+}
+
+/// An error generating wide string constants.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+}
+
+#[derive(Debug)]
+enum ErrorKind {
+    InvalidName(String),
+    DuplicateName(String),
+    Nul(String),
+    OutDir,
+    Io(PathBuf, io::Error),
+}
+
+impl Error {
+    fn new(kind: ErrorKind) -> Self {
+        Error { kind }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::InvalidName(name) => write!(f, "`{}` is not a valid constant name", name),
+            ErrorKind::DuplicateName(name) => write!(f, "duplicate constant name `{}`", name),
+            ErrorKind::Nul(name) => {
+                write!(f, "C-style string `{}` cannot contain nul characters", name)
+            }
+            ErrorKind::OutDir => {
+                f.write_str("`OUT_DIR` is not set, expected to run in a build script")
+            }
+            ErrorKind::Io(path, err) => write!(f, "couldn't write {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
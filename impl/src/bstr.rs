@@ -0,0 +1,28 @@
+use proc_macro2::TokenStream;
+use syn::{Error, Result};
+
+use crate::encode::Encode;
+use crate::parse::BstrInput;
+
+// Expands to a reference to a `BSTR` layout constant, coerced to the unsized
+// `Bstr` so the length is not part of its type.
+pub fn expand_bstr(input: BstrInput) -> Result<TokenStream> {
+    let BstrInput { krate, literal, .. } = input;
+
+    let units = u16::encode_str_c(&literal.value());
+    if units.len() - 1 > (u32::MAX / 2) as usize {
+        return Err(Error::new(
+            literal.span(),
+            "string is too long for the length prefix of a `BSTR`",
+        ));
+    }
+
+    let len = units.len();
+
+    Ok(quote::quote! {
+        {
+            const BSTR: &#krate::Bstr<[u16; #len]> = &#krate::Bstr::from_units([#(#units),*]);
+            BSTR as &#krate::Bstr
+        }
+    })
+}
@@ -0,0 +1,422 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::{Span, TokenStream};
+use syn::{Error, Ident, LitStr, Result};
+
+use crate::encode::Encode;
+use crate::parse::{CatalogInput, WCharType};
+
+// A translated string and the line it was defined on.
+struct Message {
+    value: String,
+    line: usize,
+}
+
+type Catalog = BTreeMap<String, Message>;
+
+pub fn expand_catalog(input: CatalogInput) -> Result<TokenStream> {
+    fn quote_catalog<T: Encode>(input: CatalogInput) -> Result<TokenStream> {
+        let CatalogInput {
+            attrs,
+            vis,
+            name,
+            file_path,
+            reference,
+            ..
+        } = input;
+
+        let catalog = read_catalog(&file_path)?;
+        let mut tracked = vec![crate::tracked_path(&file_path)?];
+
+        if let Some((_, _, _, reference)) = reference {
+            check_keys(&catalog, &read_catalog(&reference)?, &file_path)?;
+            tracked.push(crate::tracked_path(&reference)?);
+        }
+
+        // Keys map to constant names, which must be unique, and must not collide
+        // with the table of entries generated alongside them.
+        let mut names: BTreeMap<String, &str> = BTreeMap::new();
+        for key in catalog.keys() {
+            let name = const_name(key).ok_or_else(|| {
+                Error::new(
+                    file_path.span(),
+                    format_args!("cannot derive a constant name from the key `{}`", key),
+                )
+            })?;
+
+            if name == "ENTRIES" {
+                return Err(Error::new(
+                    file_path.span(),
+                    format_args!(
+                        "the key `{}` maps to the constant name `ENTRIES`, which is reserved for the table of entries",
+                        key
+                    ),
+                ));
+            }
+
+            if let Some(other) = names.insert(name.clone(), key) {
+                return Err(Error::new(
+                    file_path.span(),
+                    format_args!(
+                        "the keys `{}` and `{}` both map to the constant name `{}`",
+                        other, key, name
+                    ),
+                ));
+            }
+        }
+
+        let ty = T::wchar_type();
+        let mut consts = Vec::with_capacity(catalog.len());
+        let mut entries = Vec::with_capacity(catalog.len());
+
+        // The catalog is sorted by key, as required by the lookup.
+        for (key, message) in &catalog {
+            let ident = Ident::new(&const_name(key).unwrap(), Span::call_site());
+            let chars = T::encode_str(&message.value);
+
+            consts.push(quote::quote! {
+                #[doc = #key]
+                pub const #ident: &[#ty] = &[#(#chars),*];
+            });
+            entries.push(quote::quote! { (#key, #ident) });
+        }
+
+        Ok(quote::quote! {
+            #(#attrs)*
+            #vis mod #name {
+                // Including the catalogs as bytes makes Cargo rebuild the crate
+                // when one of them changes.
+                #(const _: &[u8] = ::core::include_bytes!(#tracked);)*
+
+                #(#consts)*
+
+                /// The keys and strings of the catalog, sorted by key.
+                pub static ENTRIES: &[(&str, &[#ty])] = &[#(#entries),*];
+
+                /// Returns the string with the given key, or `None` if it is not in the catalog.
+                pub fn get(key: &str) -> ::core::option::Option<&'static [#ty]> {
+                    ENTRIES
+                        .binary_search_by_key(&key, |&(key, _)| key)
+                        .ok()
+                        .map(|index| ENTRIES[index].1)
+                }
+            }
+        })
+    }
+
+    match input.ty {
+        WCharType::U16(_) => quote_catalog::<u16>(input),
+        WCharType::U32(_) => quote_catalog::<u32>(input),
+        WCharType::I16(_) => quote_catalog::<i16>(input),
+        WCharType::I32(_) => quote_catalog::<i32>(input),
+    }
+}
+
+fn read_catalog(path: &LitStr) -> Result<Catalog> {
+    let text = crate::read_file(path)?;
+    let file = path.value();
+
+    let result = if file.ends_with(".po") {
+        parse_po(&text)
+    } else if file.ends_with(".properties") {
+        parse_properties(&text)
+    } else {
+        return Err(Error::new(
+            path.span(),
+            "unsupported catalog format, expected a `.po` or `.properties` file",
+        ));
+    };
+
+    let messages = result.map_err(|(line, msg)| {
+        Error::new(path.span(), format_args!("{}:{}: {}", file, line, msg))
+    })?;
+
+    let mut catalog = Catalog::new();
+    for (key, message) in messages {
+        let line = message.line;
+        if let Some(first) = catalog.insert(key.clone(), message) {
+            return Err(Error::new(
+                path.span(),
+                format_args!(
+                    "{}:{}: duplicate key `{}`, first defined on line {}",
+                    file, line, key, first.line
+                ),
+            ));
+        }
+    }
+
+    Ok(catalog)
+}
+
+// Reports the keys that are missing from the catalog, or that are not in the
+// reference catalog.
+fn check_keys(catalog: &Catalog, reference: &Catalog, path: &LitStr) -> Result<()> {
+    fn list<'a>(keys: impl Iterator<Item = &'a String>) -> String {
+        keys.map(|key| format!("`{}`", key))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    let missing = list(reference.keys().filter(|key| !catalog.contains_key(*key)));
+    let extra = list(catalog.keys().filter(|key| !reference.contains_key(*key)));
+
+    let msg = match (missing.is_empty(), extra.is_empty()) {
+        (true, true) => return Ok(()),
+        (false, true) => format!("missing keys from the reference catalog: {}", missing),
+        (true, false) => format!("extra keys not in the reference catalog: {}", extra),
+        (false, false) => format!(
+            "missing keys from the reference catalog: {}; extra keys not in the reference catalog: {}",
+            missing, extra
+        ),
+    };
+
+    Err(Error::new(path.span(), msg))
+}
+
+// Converts a key to an upper case constant name, replacing each run of
+// characters that are not ASCII alphanumeric with an underscore.
+fn const_name(key: &str) -> Option<String> {
+    let mut name = String::with_capacity(key.len());
+
+    for word in key.split(|c: char| !c.is_ascii_alphanumeric()) {
+        if word.is_empty() {
+            continue;
+        }
+        if !name.is_empty() {
+            name.push('_');
+        }
+        name.push_str(&word.to_ascii_uppercase());
+    }
+
+    if name.is_empty() {
+        return None;
+    }
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    Some(name)
+}
+
+type ParseResult = std::result::Result<Vec<(String, Message)>, (usize, String)>;
+
+// Parses a gettext `.po` file. The header entry, fuzzy entries and entries
+// without a translation are skipped, as they are by `msgfmt`.
+fn parse_po(text: &str) -> ParseResult {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Field {
+        Id,
+        Str,
+    }
+
+    struct Entry {
+        id: String,
+        value: String,
+        line: usize,
+        fuzzy: bool,
+    }
+
+    fn finish(messages: &mut Vec<(String, Message)>, entry: Entry) {
+        if !entry.id.is_empty() && !entry.value.is_empty() && !entry.fuzzy {
+            let message = Message {
+                value: entry.value,
+                line: entry.line,
+            };
+            messages.push((entry.id, message));
+        }
+    }
+
+    let mut messages = Vec::new();
+    let mut entry: Option<Entry> = None;
+    let mut field = None;
+    let mut fuzzy = false;
+
+    for (index, line) in text.lines().enumerate() {
+        let line_num = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(comment) = line.strip_prefix('#') {
+            if let Some(flags) = comment.strip_prefix(',') {
+                fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+            }
+            continue;
+        }
+
+        let (keyword, rest) = match line.find(|c: char| c.is_whitespace()) {
+            Some(i) if !line.starts_with('"') => (&line[..i], line[i..].trim_start()),
+            _ => ("", line),
+        };
+
+        match keyword {
+            "msgid" => {
+                if let Some(entry) = entry.take() {
+                    finish(&mut messages, entry);
+                }
+                entry = Some(Entry {
+                    id: String::new(),
+                    value: String::new(),
+                    line: line_num,
+                    fuzzy,
+                });
+                fuzzy = false;
+                field = Some(Field::Id);
+            }
+            "msgstr" if field == Some(Field::Id) => field = Some(Field::Str),
+            "msgstr" => return Err((line_num, "`msgstr` without a `msgid`".into())),
+            "msgctxt" | "msgid_plural" => {
+                return Err((line_num, format!("`{}` is not supported", keyword)));
+            }
+            "" if field.is_some() => {}
+            "" => return Err((line_num, "string without a `msgid` or `msgstr`".into())),
+            _ => return Err((line_num, format!("unknown keyword `{}`", keyword))),
+        }
+
+        let string = parse_po_string(rest).map_err(|msg| (line_num, msg))?;
+        let entry = entry.as_mut().unwrap();
+        match field {
+            Some(Field::Id) => entry.id.push_str(&string),
+            _ => entry.value.push_str(&string),
+        }
+    }
+
+    if let Some(entry) = entry {
+        finish(&mut messages, entry);
+    }
+
+    Ok(messages)
+}
+
+// Parses a quoted `.po` string, with C-style escapes.
+fn parse_po_string(s: &str) -> std::result::Result<String, String> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| String::from("expected a quoted string"))?;
+
+    let mut string = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => string.push(match chars.next() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('r') => '\r',
+                Some('a') => '\x07',
+                Some('b') => '\x08',
+                Some('f') => '\x0C',
+                Some('v') => '\x0B',
+                Some(c @ '"') | Some(c @ '\\') => c,
+                Some(c) => return Err(format!("unknown escape sequence `\\{}`", c)),
+                None => return Err("unterminated escape sequence".into()),
+            }),
+            '"' => return Err("unescaped quote in string".into()),
+            c => string.push(c),
+        }
+    }
+
+    Ok(string)
+}
+
+// Parses a `.properties` file of `key = value` or `key: value` lines, with
+// `#` and `!` comments, line continuations and Java's escapes.
+fn parse_properties(text: &str) -> ParseResult {
+    let mut messages = Vec::new();
+    let mut lines = text.lines().enumerate();
+
+    while let Some((index, line)) = lines.next() {
+        let line_num = index + 1;
+        let line = line.trim_start();
+
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            continue;
+        }
+
+        // Join continuation lines, which end in an odd number of backslashes.
+        let mut logical = String::from(line);
+        while logical.chars().rev().take_while(|&c| c == '\\').count() % 2 == 1 {
+            logical.pop();
+            match lines.next() {
+                Some((_, next)) => logical.push_str(next.trim_start()),
+                None => break,
+            }
+        }
+
+        let (key, value) = split_property(&logical);
+        let key = unescape_property(key).map_err(|msg| (line_num, msg))?;
+        let value = unescape_property(value).map_err(|msg| (line_num, msg))?;
+
+        messages.push((
+            key,
+            Message {
+                value,
+                line: line_num,
+            },
+        ));
+    }
+
+    Ok(messages)
+}
+
+// Splits a property at the first unescaped `=`, `:` or whitespace.
+fn split_property(line: &str) -> (&str, &str) {
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '=' | ':' => return (&line[..i], line[i + 1..].trim_start()),
+            c if c.is_whitespace() => {
+                let rest = line[i..].trim_start();
+                let rest = match rest.strip_prefix(|c| c == '=' || c == ':') {
+                    Some(rest) => rest.trim_start(),
+                    None => rest,
+                };
+                return (&line[..i], rest);
+            }
+            _ => {}
+        }
+    }
+
+    (line, "")
+}
+
+fn unescape_property(s: &str) -> std::result::Result<String, String> {
+    // Unicode escapes are UTF-16 code units, where a surrogate pair is written
+    // as two escapes, so the string is built as UTF-16.
+    let mut units = Vec::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        let c = match c {
+            '\\' => match chars.next() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('r') => '\r',
+                Some('f') => '\x0C',
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    match u16::from_str_radix(&hex, 16) {
+                        Ok(unit) if hex.len() == 4 => units.push(unit),
+                        _ => return Err(format!("invalid unicode escape `\\u{}`", hex)),
+                    }
+                    continue;
+                }
+                // Any other escaped character stands for itself.
+                Some(c) => c,
+                None => break,
+            },
+            c => c,
+        };
+
+        let mut buf = [0; 2];
+        units.extend_from_slice(c.encode_utf16(&mut buf));
+    }
+
+    String::from_utf16(&units).map_err(|_| "unpaired surrogate in unicode escape".into())
+}
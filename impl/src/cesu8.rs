@@ -0,0 +1,45 @@
+use proc_macro2::TokenStream;
+use syn::{Error, LitStr, Result};
+
+use crate::encode::Encode;
+
+// Encodes the string as CESU-8, where each UTF-16 code unit is encoded as if it
+// were a character, so supplementary characters become a pair of 3 byte
+// surrogates. Modified UTF-8 additionally encodes nul as `C0 80`.
+pub fn expand_cesu8(literal: LitStr, modified: bool, nul_terminated: bool) -> Result<TokenStream> {
+    let text = literal.value();
+
+    // Modified UTF-8 never contains a nul byte, so any nul character is fine.
+    if nul_terminated && !modified && text.as_bytes().contains(&0) {
+        return Err(Error::new(
+            literal.span(),
+            "C-style string cannot contain nul characters",
+        ));
+    }
+
+    let mut bytes = Vec::with_capacity(text.len() + 1);
+    for unit in u16::encode_str(&text) {
+        encode_unit(&mut bytes, unit, modified);
+    }
+
+    if nul_terminated {
+        bytes.push(0);
+    }
+
+    Ok(quote::quote! { &[#(#bytes),*] })
+}
+
+fn encode_unit(bytes: &mut Vec<u8>, unit: u16, modified: bool) {
+    match unit {
+        0 if modified => bytes.extend_from_slice(&[0xC0, 0x80]),
+        0x0000..=0x007F => bytes.push(unit as u8),
+        0x0080..=0x07FF => {
+            bytes.extend_from_slice(&[0xC0 | (unit >> 6) as u8, 0x80 | (unit & 0x3F) as u8])
+        }
+        _ => bytes.extend_from_slice(&[
+            0xE0 | (unit >> 12) as u8,
+            0x80 | ((unit >> 6) & 0x3F) as u8,
+            0x80 | (unit & 0x3F) as u8,
+        ]),
+    }
+}
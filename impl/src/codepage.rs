@@ -0,0 +1,236 @@
+use proc_macro2::{Span, TokenStream};
+use syn::{Error, LitByte, LitByteStr, LitChar, LitStr, Result};
+
+use crate::parse::{CpInput, SourceEncoding};
+
+// Code page tables map bytes 0x80 to 0xFF to code points, with bytes below 0x80
+// mapping to ASCII. A zero entry marks an undefined byte.
+
+#[rustfmt::skip]
+const CP437: [u16; 128] = [
+    0x00C7, 0x00FC, 0x00E9, 0x00E2, 0x00E4, 0x00E0, 0x00E5, 0x00E7,
+    0x00EA, 0x00EB, 0x00E8, 0x00EF, 0x00EE, 0x00EC, 0x00C4, 0x00C5,
+    0x00C9, 0x00E6, 0x00C6, 0x00F4, 0x00F6, 0x00F2, 0x00FB, 0x00F9,
+    0x00FF, 0x00D6, 0x00DC, 0x00A2, 0x00A3, 0x00A5, 0x20A7, 0x0192,
+    0x00E1, 0x00ED, 0x00F3, 0x00FA, 0x00F1, 0x00D1, 0x00AA, 0x00BA,
+    0x00BF, 0x2310, 0x00AC, 0x00BD, 0x00BC, 0x00A1, 0x00AB, 0x00BB,
+    0x2591, 0x2592, 0x2593, 0x2502, 0x2524, 0x2561, 0x2562, 0x2556,
+    0x2555, 0x2563, 0x2551, 0x2557, 0x255D, 0x255C, 0x255B, 0x2510,
+    0x2514, 0x2534, 0x252C, 0x251C, 0x2500, 0x253C, 0x255E, 0x255F,
+    0x255A, 0x2554, 0x2569, 0x2566, 0x2560, 0x2550, 0x256C, 0x2567,
+    0x2568, 0x2564, 0x2565, 0x2559, 0x2558, 0x2552, 0x2553, 0x256B,
+    0x256A, 0x2518, 0x250C, 0x2588, 0x2584, 0x258C, 0x2590, 0x2580,
+    0x03B1, 0x00DF, 0x0393, 0x03C0, 0x03A3, 0x03C3, 0x00B5, 0x03C4,
+    0x03A6, 0x0398, 0x03A9, 0x03B4, 0x221E, 0x03C6, 0x03B5, 0x2229,
+    0x2261, 0x00B1, 0x2265, 0x2264, 0x2320, 0x2321, 0x00F7, 0x2248,
+    0x00B0, 0x2219, 0x00B7, 0x221A, 0x207F, 0x00B2, 0x25A0, 0x00A0,
+];
+
+#[rustfmt::skip]
+const CP850: [u16; 128] = [
+    0x00C7, 0x00FC, 0x00E9, 0x00E2, 0x00E4, 0x00E0, 0x00E5, 0x00E7,
+    0x00EA, 0x00EB, 0x00E8, 0x00EF, 0x00EE, 0x00EC, 0x00C4, 0x00C5,
+    0x00C9, 0x00E6, 0x00C6, 0x00F4, 0x00F6, 0x00F2, 0x00FB, 0x00F9,
+    0x00FF, 0x00D6, 0x00DC, 0x00F8, 0x00A3, 0x00D8, 0x00D7, 0x0192,
+    0x00E1, 0x00ED, 0x00F3, 0x00FA, 0x00F1, 0x00D1, 0x00AA, 0x00BA,
+    0x00BF, 0x00AE, 0x00AC, 0x00BD, 0x00BC, 0x00A1, 0x00AB, 0x00BB,
+    0x2591, 0x2592, 0x2593, 0x2502, 0x2524, 0x00C1, 0x00C2, 0x00C0,
+    0x00A9, 0x2563, 0x2551, 0x2557, 0x255D, 0x00A2, 0x00A5, 0x2510,
+    0x2514, 0x2534, 0x252C, 0x251C, 0x2500, 0x253C, 0x00E3, 0x00C3,
+    0x255A, 0x2554, 0x2569, 0x2566, 0x2560, 0x2550, 0x256C, 0x00A4,
+    0x00F0, 0x00D0, 0x00CA, 0x00CB, 0x00C8, 0x0131, 0x00CD, 0x00CE,
+    0x00CF, 0x2518, 0x250C, 0x2588, 0x2584, 0x00A6, 0x00CC, 0x2580,
+    0x00D3, 0x00DF, 0x00D4, 0x00D2, 0x00F5, 0x00D5, 0x00B5, 0x00FE,
+    0x00DE, 0x00DA, 0x00DB, 0x00D9, 0x00FD, 0x00DD, 0x00AF, 0x00B4,
+    0x00AD, 0x00B1, 0x2017, 0x00BE, 0x00B6, 0x00A7, 0x00F7, 0x00B8,
+    0x00B0, 0x00A8, 0x00B7, 0x00B9, 0x00B3, 0x00B2, 0x25A0, 0x00A0,
+];
+
+#[rustfmt::skip]
+const CP1252: [u16; 128] = [
+    0x20AC, 0x0000, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021,
+    0x02C6, 0x2030, 0x0160, 0x2039, 0x0152, 0x0000, 0x017D, 0x0000,
+    0x0000, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x0000, 0x017E, 0x0178,
+    0x00A0, 0x00A1, 0x00A2, 0x00A3, 0x00A4, 0x00A5, 0x00A6, 0x00A7,
+    0x00A8, 0x00A9, 0x00AA, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF,
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x00B4, 0x00B5, 0x00B6, 0x00B7,
+    0x00B8, 0x00B9, 0x00BA, 0x00BB, 0x00BC, 0x00BD, 0x00BE, 0x00BF,
+    0x00C0, 0x00C1, 0x00C2, 0x00C3, 0x00C4, 0x00C5, 0x00C6, 0x00C7,
+    0x00C8, 0x00C9, 0x00CA, 0x00CB, 0x00CC, 0x00CD, 0x00CE, 0x00CF,
+    0x00D0, 0x00D1, 0x00D2, 0x00D3, 0x00D4, 0x00D5, 0x00D6, 0x00D7,
+    0x00D8, 0x00D9, 0x00DA, 0x00DB, 0x00DC, 0x00DD, 0x00DE, 0x00DF,
+    0x00E0, 0x00E1, 0x00E2, 0x00E3, 0x00E4, 0x00E5, 0x00E6, 0x00E7,
+    0x00E8, 0x00E9, 0x00EA, 0x00EB, 0x00EC, 0x00ED, 0x00EE, 0x00EF,
+    0x00F0, 0x00F1, 0x00F2, 0x00F3, 0x00F4, 0x00F5, 0x00F6, 0x00F7,
+    0x00F8, 0x00F9, 0x00FA, 0x00FB, 0x00FC, 0x00FD, 0x00FE, 0x00FF,
+];
+
+#[derive(Clone, Copy)]
+enum CodePage {
+    Cp437,
+    Cp850,
+    Cp1252,
+}
+
+impl CodePage {
+    fn from_number(number: u16) -> Option<CodePage> {
+        match number {
+            437 => Some(CodePage::Cp437),
+            850 => Some(CodePage::Cp850),
+            1252 => Some(CodePage::Cp1252),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            CodePage::Cp437 => "code page 437",
+            CodePage::Cp850 => "code page 850",
+            CodePage::Cp1252 => "Windows-1252",
+        }
+    }
+
+    fn table(self) -> &'static [u16; 128] {
+        match self {
+            CodePage::Cp437 => &CP437,
+            CodePage::Cp850 => &CP850,
+            CodePage::Cp1252 => &CP1252,
+        }
+    }
+
+    fn decode_byte(self, b: u8) -> Option<char> {
+        if b < 0x80 {
+            return Some(b as char);
+        }
+
+        match self.table()[b as usize - 0x80] {
+            0 => None,
+            c => char::from_u32(c as u32),
+        }
+    }
+
+    fn encode_char(self, c: char) -> Option<u8> {
+        if c.is_ascii() {
+            return Some(c as u8);
+        }
+
+        self.table()
+            .iter()
+            .position(|&entry| entry != 0 && entry as u32 == c as u32)
+            .map(|i| 0x80 + i as u8)
+    }
+}
+
+impl SourceEncoding {
+    fn name(self) -> &'static str {
+        match self {
+            SourceEncoding::Latin1 => "ISO-8859-1",
+            SourceEncoding::Cp1252 => CodePage::Cp1252.name(),
+        }
+    }
+
+    fn decode_byte(self, b: u8) -> Option<char> {
+        match self {
+            SourceEncoding::Latin1 => Some(b as char),
+            SourceEncoding::Cp1252 => CodePage::Cp1252.decode_byte(b),
+        }
+    }
+
+    fn decode(self, bytes: &[u8], span: Span) -> Result<String> {
+        bytes
+            .iter()
+            .map(|&b| {
+                self.decode_byte(b).ok_or_else(|| {
+                    Error::new(
+                        span,
+                        format_args!("byte 0x{:02X} is not defined in {}", b, self.name()),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+// Returns an error if a source encoding was given, for inputs other than byte
+// literals.
+pub fn reject_source(source: Option<(SourceEncoding, Span)>) -> Result<()> {
+    match source {
+        Some((_, span)) => Err(Error::new(
+            span,
+            "a source encoding can only be used with byte literals",
+        )),
+        None => Ok(()),
+    }
+}
+
+fn require_source(source: Option<(SourceEncoding, Span)>, span: Span) -> Result<SourceEncoding> {
+    match source {
+        Some((encoding, _)) => Ok(encoding),
+        None => Err(Error::new(
+            span,
+            "byte literals require a source encoding, either `latin1` or `cp1252`",
+        )),
+    }
+}
+
+// Decodes a byte string literal into the equivalent string literal.
+pub fn decode_byte_str(source: Option<(SourceEncoding, Span)>, lit: &LitByteStr) -> Result<LitStr> {
+    let encoding = require_source(source, lit.span())?;
+    let text = encoding.decode(&lit.value(), lit.span())?;
+    Ok(LitStr::new(&text, lit.span()))
+}
+
+// Decodes a byte literal into the equivalent character literal.
+pub fn decode_byte(source: Option<(SourceEncoding, Span)>, lit: &LitByte) -> Result<LitChar> {
+    let encoding = require_source(source, lit.span())?;
+    let text = encoding.decode(&[lit.value()], lit.span())?;
+    let c = text.chars().next().unwrap();
+    Ok(LitChar::new(c, lit.span()))
+}
+
+pub fn expand_cp(input: CpInput, nul_terminated: bool) -> Result<TokenStream> {
+    let CpInput { page, literal, .. } = input;
+
+    let code_page = match CodePage::from_number(page.base10_parse()?) {
+        Some(code_page) => code_page,
+        None => {
+            return Err(Error::new(
+                page.span(),
+                "unsupported code page, expected one of 437, 850 or 1252",
+            ));
+        }
+    };
+
+    let text = literal.value();
+
+    if nul_terminated && text.as_bytes().contains(&0) {
+        return Err(Error::new(
+            literal.span(),
+            "C-style string cannot contain nul characters",
+        ));
+    }
+
+    let mut bytes = Vec::with_capacity(text.len() + 1);
+    for c in text.chars() {
+        match code_page.encode_char(c) {
+            Some(b) => bytes.push(b),
+            None => {
+                return Err(Error::new(
+                    literal.span(),
+                    format_args!(
+                        "character {:?} cannot be represented in {}",
+                        c,
+                        code_page.name()
+                    ),
+                ));
+            }
+        }
+    }
+
+    if nul_terminated {
+        bytes.push(0);
+    }
+
+    Ok(quote::quote! { &[#(#bytes),*] })
+}
@@ -0,0 +1,57 @@
+use proc_macro2::TokenStream;
+use syn::{Error, Result};
+
+use crate::encode::Encode;
+use crate::parse::{CountedInput, WCharType};
+
+// Expands to a single array holding the length prefix followed by the string.
+// A prefix wider than the character type is split across several units in
+// the target's native byte order, a narrower one takes up a whole unit.
+pub fn expand_counted(input: CountedInput) -> Result<TokenStream> {
+    fn quote_counted<T: Encode>(input: CountedInput) -> Result<TokenStream> {
+        let CountedInput {
+            count_ty, literal, ..
+        } = input;
+
+        let chars = T::encode_str(&literal.value());
+        let count = chars.len();
+
+        let bits = count_ty.bits();
+        if bits < 32 && count >> bits != 0 {
+            return Err(Error::new(
+                literal.span(),
+                format_args!(
+                    "string of {} code units is too long for a `{}` length prefix",
+                    count,
+                    quote::quote!(#count_ty),
+                ),
+            ));
+        }
+
+        // Split the count into units, least significant first.
+        let prefix_len = (bits / T::BITS).max(1);
+        let prefix: Vec<T> = (0..prefix_len)
+            .map(|i| T::from_unit((count as u64 >> (i * T::BITS)) as u32))
+            .collect();
+
+        if prefix_len == 1 {
+            return Ok(quote::quote! { &[#(#prefix,)* #(#chars),*] });
+        }
+
+        let prefix_rev = prefix.iter().rev();
+        Ok(quote::quote! {
+            if cfg!(target_endian = "little") {
+                &[#(#prefix,)* #(#chars),*]
+            } else {
+                &[#(#prefix_rev,)* #(#chars),*]
+            }
+        })
+    }
+
+    match input.ty {
+        WCharType::U16(_) => quote_counted::<u16>(input),
+        WCharType::U32(_) => quote_counted::<u32>(input),
+        WCharType::I16(_) => quote_counted::<i16>(input),
+        WCharType::I32(_) => quote_counted::<i32>(input),
+    }
+}
@@ -0,0 +1,173 @@
+use proc_macro2::TokenStream;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, LitStr, Result, Token};
+
+use crate::encode::{self, Encode};
+use crate::parse::WCharType;
+
+mod kw {
+    syn::custom_keyword!(nul);
+    syn::custom_keyword!(rename);
+}
+
+// Container attribute: `#[wide(u16)]` or `#[wide(u16, nul)]`.
+struct ContainerAttr {
+    ty: WCharType,
+    nul: bool,
+}
+
+impl Parse for ContainerAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ty = input.parse()?;
+
+        let nul = if input.is_empty() {
+            false
+        } else {
+            input.parse::<Token![,]>()?;
+            input.parse::<kw::nul>()?;
+            true
+        };
+
+        Ok(ContainerAttr { ty, nul })
+    }
+}
+
+// Variant attribute: `#[wide(rename = "...")]`.
+struct VariantAttr {
+    rename: LitStr,
+}
+
+impl Parse for VariantAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<kw::rename>()?;
+        input.parse::<Token![=]>()?;
+
+        Ok(VariantAttr {
+            rename: input.parse()?,
+        })
+    }
+}
+
+fn wide_attr(attrs: &[Attribute]) -> Result<Option<&Attribute>> {
+    let mut wide = attrs.iter().filter(|attr| attr.path.is_ident("wide"));
+
+    let first = wide.next();
+    if let Some(duplicate) = wide.next() {
+        return Err(Error::new_spanned(
+            duplicate,
+            "duplicate `#[wide(...)]` attribute",
+        ));
+    }
+
+    Ok(first)
+}
+
+pub fn expand_wide_name(input: DeriveInput) -> Result<TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`WideName` can only be derived for enums",
+            ));
+        }
+    };
+
+    let ContainerAttr { ty, nul } = match wide_attr(&input.attrs)? {
+        Some(attr) => attr.parse_args()?,
+        None => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "missing `#[wide(...)]` attribute specifying the wide character type",
+            ));
+        }
+    };
+
+    let mut names: Vec<(&Ident, LitStr)> = Vec::with_capacity(data.variants.len());
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "`WideName` can only be derived for enums with unit variants",
+            ));
+        }
+
+        // Raw identifiers are named without their `r#` prefix.
+        let name = match wide_attr(&variant.attrs)? {
+            Some(attr) => attr.parse_args::<VariantAttr>()?.rename,
+            None => LitStr::new(&variant.ident.unraw().to_string(), variant.ident.span()),
+        };
+
+        if nul && name.value().as_bytes().contains(&0) {
+            return Err(Error::new(
+                name.span(),
+                "C-style string cannot contain nul characters",
+            ));
+        }
+
+        if let Some((_, other)) = names
+            .iter()
+            .find(|(_, other)| other.value() == name.value())
+        {
+            let mut err = Error::new(name.span(), "duplicate wide name");
+            err.combine(Error::new(other.span(), "first used here"));
+            return Err(err);
+        }
+
+        names.push((&variant.ident, name));
+    }
+
+    fn quote_impl<T: Encode>(
+        input: &DeriveInput,
+        names: &[(&Ident, LitStr)],
+        nul: bool,
+    ) -> TokenStream {
+        let ident = &input.ident;
+        let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+        let ty = T::wchar_type();
+        let variants = names.iter().map(|(variant, _)| variant);
+        let encoded = names.iter().map(|(_, name)| {
+            if nul {
+                T::encode_str_c(&name.value())
+            } else {
+                T::encode_str(&name.value())
+            }
+        });
+
+        let wide_names = encoded
+            .clone()
+            .map(|chars| quote::quote! { &[#(#chars),*] });
+        let patterns = encoded.map(|chars| encode::quote_pat(&chars, false));
+
+        let variants2 = variants.clone();
+
+        quote::quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Returns the wide string name of the variant.
+                pub fn wide_name(&self) -> &'static [#ty] {
+                    match *self {
+                        #(#ident::#variants => #wide_names,)*
+                    }
+                }
+
+                /// Returns the variant with the given wide string name.
+                pub fn from_wide(name: &[#ty]) -> ::core::option::Option<Self> {
+                    match name {
+                        #(#patterns => ::core::option::Option::Some(#ident::#variants2),)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(match ty {
+        WCharType::U16(_) => quote_impl::<u16>(&input, &names, nul),
+        WCharType::U32(_) => quote_impl::<u32>(&input, &names, nul),
+        WCharType::I16(_) => quote_impl::<i16>(&input, &names, nul),
+        WCharType::I32(_) => quote_impl::<i32>(&input, &names, nul),
+    })
+}
@@ -0,0 +1,149 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Span, TokenStream};
+use syn::{Error, Result};
+
+use crate::encode::Encode;
+use crate::parse::{DirInput, WCharType};
+
+pub fn expand_dir(input: DirInput) -> Result<TokenStream> {
+    fn quote_dir<T: Encode>(input: DirInput) -> Result<TokenStream> {
+        let DirInput {
+            krate,
+            dir_path,
+            glob,
+            ..
+        } = input;
+
+        let span = dir_path.span();
+        let root = crate::resolve_path(&dir_path)?;
+
+        let mut files = Vec::new();
+        walk_dir(&root, String::new(), &mut files, span)?;
+
+        if let Some((_, _, _, glob)) = glob {
+            let pattern: Vec<char> = glob.value().chars().collect();
+            files.retain(|(name, _)| glob_match(&pattern, &name.chars().collect::<Vec<_>>()));
+        }
+
+        // The lookup performs a binary search, so the entries must be sorted.
+        files.sort();
+
+        let ty = T::wchar_type();
+        let mut tracked = Vec::with_capacity(files.len());
+        let mut entries = Vec::with_capacity(files.len());
+
+        for (name, path) in files {
+            let chars = T::encode_str(&crate::read_path(&path, span)?);
+
+            let path = path.to_str().ok_or_else(|| {
+                Error::new(
+                    span,
+                    format_args!("path {} is not valid UTF-8", path.display()),
+                )
+            })?;
+
+            tracked.push(path.to_owned());
+            entries.push(quote::quote! { (#name, &[#(#chars),*] as &[#ty]) });
+        }
+
+        // Including the files as bytes makes Cargo rebuild the crate when one of
+        // them changes. Files added to the directory are not tracked.
+        Ok(quote::quote! {
+            {
+                #(const _: &[u8] = ::core::include_bytes!(#tracked);)*
+
+                #krate::WStrDir::<#ty>::from_sorted_entries(&[#(#entries),*])
+            }
+        })
+    }
+
+    match input.ty {
+        WCharType::U16(_) => quote_dir::<u16>(input),
+        WCharType::U32(_) => quote_dir::<u32>(input),
+        WCharType::I16(_) => quote_dir::<i16>(input),
+        WCharType::I32(_) => quote_dir::<i32>(input),
+    }
+}
+
+// Collects the files in a directory and its subdirectories, along with their
+// paths relative to the root directory, separated by `/`. Symbolic links are
+// skipped, as following them could loop forever.
+fn walk_dir(
+    dir: &Path,
+    prefix: String,
+    files: &mut Vec<(String, PathBuf)>,
+    span: Span,
+) -> Result<()> {
+    let error = |err: std::io::Error| {
+        Error::new(
+            span,
+            format_args!("couldn't read directory {}: {}", dir.display(), err),
+        )
+    };
+
+    for entry in fs::read_dir(dir).map_err(error)? {
+        let entry = entry.map_err(error)?;
+        let path = entry.path();
+
+        let file_name = entry.file_name().into_string().map_err(|_| {
+            Error::new(
+                span,
+                format_args!("file name of {} is not valid UTF-8", path.display()),
+            )
+        })?;
+        let name = format!("{}{}", prefix, file_name);
+
+        let file_type = entry.file_type().map_err(error)?;
+        if file_type.is_symlink() {
+            continue;
+        } else if file_type.is_dir() {
+            walk_dir(&path, name + "/", files, span)?;
+        } else {
+            files.push((name, path));
+        }
+    }
+
+    Ok(())
+}
+
+// Matches a path against a glob pattern, where `?` matches any character and
+// `*` any number of characters, except for `/`, and `**/` matches any number
+// of directories.
+fn glob_match(pattern: &[char], name: &[char]) -> bool {
+    match pattern {
+        [] => name.is_empty(),
+        ['*', '*', '/', rest @ ..] => {
+            if glob_match(rest, name) {
+                return true;
+            }
+
+            // Skip the first directory of the path.
+            match name.iter().position(|&c| c == '/') {
+                Some(i) => glob_match(pattern, &name[i + 1..]),
+                None => false,
+            }
+        }
+        ['*', '*'] => true,
+        ['*', rest @ ..] => {
+            for i in 0..=name.len() {
+                if glob_match(rest, &name[i..]) {
+                    return true;
+                }
+                if name.get(i) == Some(&'/') {
+                    break;
+                }
+            }
+            false
+        }
+        ['?', rest @ ..] => match name {
+            [c, name @ ..] if *c != '/' => glob_match(rest, name),
+            _ => false,
+        },
+        [p, rest @ ..] => match name {
+            [c, name @ ..] if c == p => glob_match(rest, name),
+            _ => false,
+        },
+    }
+}
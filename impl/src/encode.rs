@@ -1,9 +1,9 @@
 use std::any::type_name;
-use std::iter::once;
 
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::{Error, LitChar, Result};
+use wchar_build::encode::Unit;
 
 use crate::parse::WCharType;
 
@@ -57,73 +57,71 @@ pub fn expand_str_c(ty: WCharType, text: &str) -> TokenStream {
     }
 }
 
-pub trait Encode: Copy + ToTokens {
-    fn wchar_type() -> WCharType;
-
-    fn encode_char(c: char) -> Option<Self>;
-
-    fn encode_str(s: &str) -> Vec<Self>;
-
-    fn encode_str_c(s: &str) -> Vec<Self>;
-}
-
-impl Encode for u16 {
-    fn wchar_type() -> WCharType {
-        syn::parse_quote!(u16)
+pub fn expand_str_pat(ty: WCharType, text: &str, rest: bool) -> TokenStream {
+    fn quote_str_pat<T: Encode>(text: &str, rest: bool) -> TokenStream {
+        quote_pat(&T::encode_str(text), rest)
     }
 
-    fn encode_char(c: char) -> Option<Self> {
-        if c.len_utf16() == 1 {
-            let mut buf = [0; 1];
-            c.encode_utf16(&mut buf);
-            Some(buf[0])
-        } else {
-            None
-        }
+    match ty {
+        WCharType::U16(_) => quote_str_pat::<u16>(text, rest),
+        WCharType::U32(_) => quote_str_pat::<u32>(text, rest),
+        WCharType::I16(_) => quote_str_pat::<i16>(text, rest),
+        WCharType::I32(_) => quote_str_pat::<i32>(text, rest),
     }
+}
 
-    fn encode_str(s: &str) -> Vec<Self> {
-        s.encode_utf16().collect()
+pub fn expand_str_c_pat(ty: WCharType, text: &str, rest: bool) -> TokenStream {
+    fn quote_str_c_pat<T: Encode>(text: &str, rest: bool) -> TokenStream {
+        quote_pat(&T::encode_str_c(text), rest)
     }
 
-    fn encode_str_c(s: &str) -> Vec<Self> {
-        s.encode_utf16().chain(once(0)).collect()
+    match ty {
+        WCharType::U16(_) => quote_str_c_pat::<u16>(text, rest),
+        WCharType::U32(_) => quote_str_c_pat::<u32>(text, rest),
+        WCharType::I16(_) => quote_str_c_pat::<i16>(text, rest),
+        WCharType::I32(_) => quote_str_c_pat::<i32>(text, rest),
     }
 }
 
-impl Encode for u32 {
-    fn wchar_type() -> WCharType {
-        syn::parse_quote!(u32)
+pub fn quote_pat<T: Encode>(chars: &[T], rest: bool) -> TokenStream {
+    if rest {
+        quote::quote! { [#(#chars,)* ..] }
+    } else {
+        quote::quote! { [#(#chars),*] }
     }
+}
+
+pub trait Encode: Unit + Ord + ToTokens {
+    fn wchar_type() -> WCharType;
 
     fn encode_char(c: char) -> Option<Self> {
-        Some(c as u32)
+        wchar_build::encode::encode_char(c)
     }
 
     fn encode_str(s: &str) -> Vec<Self> {
-        s.chars().map(|c| c as u32).collect()
+        wchar_build::encode::encode_str(s)
     }
 
     fn encode_str_c(s: &str) -> Vec<Self> {
-        s.chars().map(|c| c as u32).chain(once(0)).collect()
+        wchar_build::encode::encode_str_c(s)
     }
 }
 
-impl Encode for i16 {
+impl Encode for u16 {
     fn wchar_type() -> WCharType {
-        syn::parse_quote!(i16)
-    }
-
-    fn encode_char(c: char) -> Option<Self> {
-        u16::encode_char(c).map(|c| c as i16)
+        syn::parse_quote!(u16)
     }
+}
 
-    fn encode_str(s: &str) -> Vec<Self> {
-        s.encode_utf16().map(|c| c as i16).collect()
+impl Encode for u32 {
+    fn wchar_type() -> WCharType {
+        syn::parse_quote!(u32)
     }
+}
 
-    fn encode_str_c(s: &str) -> Vec<Self> {
-        s.encode_utf16().map(|c| c as i16).chain(once(0)).collect()
+impl Encode for i16 {
+    fn wchar_type() -> WCharType {
+        syn::parse_quote!(i16)
     }
 }
 
@@ -131,16 +129,4 @@ impl Encode for i32 {
     fn wchar_type() -> WCharType {
         syn::parse_quote!(i32)
     }
-
-    fn encode_char(c: char) -> Option<Self> {
-        Some(c as i32)
-    }
-
-    fn encode_str(s: &str) -> Vec<Self> {
-        s.chars().map(|c| c as i32).collect()
-    }
-
-    fn encode_str_c(s: &str) -> Vec<Self> {
-        s.chars().map(|c| c as i32).chain(once(0)).collect()
-    }
 }
@@ -0,0 +1,82 @@
+use std::env::{self, VarError};
+
+use proc_macro2::TokenStream;
+use syn::{Error, LitStr, Result};
+
+use crate::encode::{self, Encode};
+use crate::parse::{EnvInput, WCharType};
+
+// The variables are read with `std::env`, which the compiler does not track, so
+// the expansions also invoke `env!` or `option_env!` for the same variable to
+// trigger a rebuild when it changes.
+pub fn expand_env(input: EnvInput, nul_terminated: bool) -> Result<TokenStream> {
+    let EnvInput { ty, name, .. } = input;
+
+    let value = match read_var(&name)? {
+        Some(value) => value,
+        None => {
+            return Err(Error::new(
+                name.span(),
+                format_args!(
+                    "environment variable `{}` not defined at compile time",
+                    name.value()
+                ),
+            ));
+        }
+    };
+
+    let expanded = if nul_terminated {
+        encode::expand_str_c(ty, &value)
+    } else {
+        encode::expand_str(ty, &value)
+    };
+
+    Ok(quote::quote! {{
+        const _: &str = ::core::env!(#name);
+        #expanded
+    }})
+}
+
+pub fn expand_option_env(input: EnvInput) -> Result<TokenStream> {
+    fn quote_option_env<T: Encode>(name: LitStr, value: Option<String>) -> TokenStream {
+        let ty = T::wchar_type();
+
+        let expanded = match value {
+            Some(value) => {
+                let chars = T::encode_str(&value);
+                quote::quote! { ::core::option::Option::Some(&[#(#chars),*] as &[#ty]) }
+            }
+            None => quote::quote! { ::core::option::Option::None::<&[#ty]> },
+        };
+
+        quote::quote! {{
+            const _: ::core::option::Option<&str> = ::core::option_env!(#name);
+            #expanded
+        }}
+    }
+
+    let EnvInput { ty, name, .. } = input;
+    let value = read_var(&name)?;
+
+    Ok(match ty {
+        WCharType::U16(_) => quote_option_env::<u16>(name, value),
+        WCharType::U32(_) => quote_option_env::<u32>(name, value),
+        WCharType::I16(_) => quote_option_env::<i16>(name, value),
+        WCharType::I32(_) => quote_option_env::<i32>(name, value),
+    })
+}
+
+// Reads a variable, which is `None` if it is not set.
+fn read_var(name: &LitStr) -> Result<Option<String>> {
+    match env::var(name.value()) {
+        Ok(value) => Ok(Some(value)),
+        Err(VarError::NotPresent) => Ok(None),
+        Err(VarError::NotUnicode(_)) => Err(Error::new(
+            name.span(),
+            format_args!(
+                "environment variable `{}` is not valid unicode",
+                name.value()
+            ),
+        )),
+    }
+}
@@ -0,0 +1,236 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+use proc_macro2::{Span, TokenStream};
+use syn::{Error, LitStr, Result};
+use wchar_build::encode::push_char;
+
+use crate::encode::Encode;
+use crate::parse::WCharType;
+
+// The escape rules used to interpret a string.
+#[derive(Clone, Copy)]
+enum Escapes {
+    C,
+    Units,
+}
+
+pub fn expand_c(ty: WCharType, lit: &LitStr, nul_terminated: bool) -> Result<TokenStream> {
+    expand(ty, &lit.value(), lit.span(), nul_terminated, Escapes::C)
+}
+
+pub fn expand_units(
+    ty: WCharType,
+    text: &str,
+    span: Span,
+    nul_terminated: bool,
+) -> Result<TokenStream> {
+    expand(ty, text, span, nul_terminated, Escapes::Units)
+}
+
+fn expand(
+    ty: WCharType,
+    text: &str,
+    span: Span,
+    nul_terminated: bool,
+    escapes: Escapes,
+) -> Result<TokenStream> {
+    fn quote_units<T: Encode>(
+        text: &str,
+        span: Span,
+        nul_terminated: bool,
+        escapes: Escapes,
+    ) -> Result<TokenStream> {
+        let units = match escapes {
+            Escapes::C => unescape_c::<T>(text),
+            Escapes::Units => unescape_units::<T>(text),
+        };
+        let mut units = units.map_err(|msg| Error::new(span, msg))?;
+
+        if nul_terminated {
+            if units.contains(&T::from_unit(0)) {
+                return Err(Error::new(
+                    span,
+                    "C-style string cannot contain nul characters",
+                ));
+            }
+            units.push(T::from_unit(0));
+        }
+
+        Ok(quote::quote! { &[#(#units),*] })
+    }
+
+    match ty {
+        WCharType::U16(_) => quote_units::<u16>(text, span, nul_terminated, escapes),
+        WCharType::U32(_) => quote_units::<u32>(text, span, nul_terminated, escapes),
+        WCharType::I16(_) => quote_units::<i16>(text, span, nul_terminated, escapes),
+        WCharType::I32(_) => quote_units::<i32>(text, span, nul_terminated, escapes),
+    }
+}
+
+// Interprets the text using C's escape rules, returning the code units a C
+// compiler emits for a wide string literal with a `wchar_t` of type `T`.
+//
+// Octal and hex escapes produce a single code unit, which may be a lone
+// surrogate, while universal character names are encoded as UTF-16 or UTF-32.
+fn unescape_c<T: Encode>(text: &str) -> std::result::Result<Vec<T>, String> {
+    let mut units = Vec::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            push_char(&mut units, c);
+            continue;
+        }
+
+        let c = match chars.next() {
+            Some(c) => c,
+            None => return Err("incomplete escape sequence at end of string".to_owned()),
+        };
+
+        let unit = match c {
+            '\'' | '"' | '?' | '\\' => c as u32,
+            'a' => 0x07,
+            'b' => 0x08,
+            'e' => 0x1B,
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            '0'..='7' => {
+                let mut value = c.to_digit(8).unwrap();
+                for _ in 0..2 {
+                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            chars.next();
+                            value = value * 8 + digit;
+                        }
+                        None => break,
+                    }
+                }
+
+                // At most three digits, so the value always fits.
+                value
+            }
+            'x' => {
+                let digits = take_hex(&mut chars, usize::MAX);
+                if digits.is_empty() {
+                    return Err("`\\x` used with no following hex digits".to_owned());
+                }
+
+                // Leading zeros don't count towards the value.
+                let significant = digits.trim_start_matches('0');
+                let value = if significant.is_empty() {
+                    Some(0)
+                } else {
+                    u32::from_str_radix(significant, 16).ok()
+                };
+
+                match value {
+                    Some(value) if T::BITS == 32 || value >> T::BITS == 0 => value,
+                    _ => {
+                        return Err(format!("hex escape sequence `\\x{}` out of range", digits));
+                    }
+                }
+            }
+            'u' | 'U' => {
+                let len = if c == 'u' { 4 } else { 8 };
+                let digits = take_hex(&mut chars, len);
+                if digits.len() != len {
+                    return Err(format!(
+                        "incomplete universal character name `\\{}{}`",
+                        c, digits
+                    ));
+                }
+
+                // C forbids naming the basic characters, and controls, other
+                // than those missing from its basic character set.
+                let value = u32::from_str_radix(&digits, 16).unwrap();
+                if value < 0xA0 && !matches!(value, 0x24 | 0x40 | 0x60) {
+                    return Err(format!(
+                        "universal character name `\\{}{}` cannot specify a character below U+00A0 other than `$`, `@` and `` ` ``",
+                        c, digits
+                    ));
+                }
+
+                match char::from_u32(value) {
+                    Some(c) => {
+                        push_char(&mut units, c);
+                        continue;
+                    }
+                    None => {
+                        return Err(format!(
+                            "`\\{}{}` is not a valid universal character",
+                            c, digits
+                        ));
+                    }
+                }
+            }
+            c => return Err(format!("unknown escape sequence `\\{}`", c)),
+        };
+
+        units.push(T::from_unit(unit));
+    }
+
+    Ok(units)
+}
+
+// Interprets `\u[XXXX]` escapes in the text, each inserting a single code unit
+// as is. All other characters are encoded as UTF-16 or UTF-32.
+fn unescape_units<T: Encode>(text: &str) -> std::result::Result<Vec<T>, String> {
+    let mut units = Vec::with_capacity(text.len());
+
+    let mut rest = text;
+    while let Some(start) = rest.find("\\u[") {
+        for c in rest[..start].chars() {
+            push_char(&mut units, c);
+        }
+        rest = &rest[start + 3..];
+
+        let end = match rest.find(']') {
+            Some(end) => end,
+            None => return Err("unterminated `\\u[...]` escape".to_owned()),
+        };
+
+        let digits = &rest[..end];
+        if digits.is_empty() || digits.len() > 8 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!(
+                "invalid code unit escape `\\u[{}]`, expected 1 to 8 hex digits",
+                digits
+            ));
+        }
+
+        let unit = u32::from_str_radix(digits, 16).unwrap();
+        if T::BITS < 32 && unit >> T::BITS != 0 {
+            return Err(format!(
+                "code unit escape `\\u[{}]` does not fit within a {} bit code unit",
+                digits,
+                T::BITS
+            ));
+        }
+        units.push(T::from_unit(unit));
+
+        rest = &rest[end + 1..];
+    }
+
+    for c in rest.chars() {
+        push_char(&mut units, c);
+    }
+
+    Ok(units)
+}
+
+fn take_hex(chars: &mut Peekable<Chars>, max: usize) -> String {
+    let mut digits = String::new();
+    while digits.len() < max {
+        match chars.peek() {
+            Some(c) if c.is_ascii_hexdigit() => {
+                digits.push(*c);
+                chars.next();
+            }
+            _ => break,
+        }
+    }
+    digits
+}
@@ -0,0 +1,375 @@
+use std::env;
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{Error, Expr, ExprLit, ExprMacro, Lit, LitStr, Result, UnOp};
+
+use crate::encode;
+use crate::parse::{FormatArg, FormatConstInput};
+
+// A format argument, evaluated at compile time.
+enum Value {
+    Str(String),
+    Char(char),
+    Int(i128),
+    Bool(bool),
+}
+
+struct Arg {
+    name: Option<String>,
+    value: Value,
+    span_tokens: TokenStream,
+    used: bool,
+}
+
+pub fn expand_format_const(input: FormatConstInput, nul_terminated: bool) -> Result<TokenStream> {
+    let FormatConstInput { ty, fmt, args, .. } = input;
+
+    // Environment variables read while evaluating `env!` arguments are tracked
+    // by emitting the `env!` invocations, so that changes trigger a rebuild.
+    let mut env_vars = Vec::new();
+
+    let mut evaluated = Vec::with_capacity(args.len());
+    for FormatArg { name, expr, .. } in args {
+        let value = eval(&expr, &mut env_vars)?;
+
+        evaluated.push(Arg {
+            name: name.map(|(name, _)| name.to_string()),
+            value,
+            span_tokens: expr.into_token_stream(),
+            used: false,
+        });
+    }
+
+    let text = format(&fmt, &mut evaluated)?;
+
+    if let Some(arg) = evaluated.iter().find(|arg| !arg.used) {
+        return Err(Error::new_spanned(&arg.span_tokens, "argument never used"));
+    }
+
+    let expanded = if nul_terminated {
+        if text.as_bytes().contains(&0) {
+            return Err(Error::new(
+                fmt.span(),
+                "C-style string cannot contain nul characters",
+            ));
+        }
+
+        encode::expand_str_c(ty, &text)
+    } else {
+        encode::expand_str(ty, &text)
+    };
+
+    if env_vars.is_empty() {
+        Ok(expanded)
+    } else {
+        Ok(quote::quote! {{
+            #(const _: &str = #env_vars;)*
+            #expanded
+        }})
+    }
+}
+
+fn eval(expr: &Expr, env_vars: &mut Vec<ExprMacro>) -> Result<Value> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Str(lit) => Ok(Value::Str(lit.value())),
+            Lit::Char(lit) => Ok(Value::Char(lit.value())),
+            Lit::Int(lit) => Ok(Value::Int(lit.base10_parse()?)),
+            Lit::Bool(lit) => Ok(Value::Bool(lit.value)),
+            _ => Err(Error::new_spanned(
+                lit,
+                "format arguments must be string, character, integer or boolean literals",
+            )),
+        },
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            match eval(&unary.expr, env_vars)? {
+                Value::Int(n) => Ok(Value::Int(-n)),
+                _ => Err(Error::new_spanned(
+                    expr,
+                    "cannot negate a non-integer literal",
+                )),
+            }
+        }
+        Expr::Group(group) => eval(&group.expr, env_vars),
+        Expr::Paren(paren) => eval(&paren.expr, env_vars),
+        Expr::Macro(mac) if mac.mac.path.is_ident("env") => {
+            let key: LitStr = mac.mac.parse_body()?;
+
+            match env::var(key.value()) {
+                Ok(value) => {
+                    env_vars.push(mac.clone());
+                    Ok(Value::Str(value))
+                }
+                Err(_) => Err(Error::new(
+                    key.span(),
+                    format_args!(
+                        "environment variable `{}` not defined at compile time",
+                        key.value()
+                    ),
+                )),
+            }
+        }
+        _ => Err(Error::new_spanned(
+            expr,
+            "format arguments must be literals or `env!` invocations, runtime values are not supported",
+        )),
+    }
+}
+
+// A parsed `{...}` format specification.
+#[derive(Default)]
+struct Spec {
+    fill: Option<char>,
+    align: Option<char>,
+    sign_plus: bool,
+    alternate: bool,
+    zero: bool,
+    width: Option<usize>,
+    precision: Option<usize>,
+    ty: Option<char>,
+}
+
+fn format(fmt: &LitStr, args: &mut [Arg]) -> Result<String> {
+    let err = |msg: &str| Error::new(fmt.span(), msg);
+
+    let text = fmt.value();
+    let mut chars = text.chars().peekable();
+
+    let mut out = String::with_capacity(text.len());
+    let mut next_positional = 0;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                out.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                out.push('}');
+            }
+            '}' => return Err(err("invalid format string: unmatched `}` found")),
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => return Err(err("invalid format string: expected `}`")),
+                    }
+                }
+
+                let (arg, spec) = match placeholder.find(':') {
+                    Some(i) => (&placeholder[..i], &placeholder[i + 1..]),
+                    None => (&placeholder[..], ""),
+                };
+                let arg = arg.trim();
+
+                let index = if arg.is_empty() {
+                    next_positional += 1;
+                    next_positional - 1
+                } else if let Ok(index) = arg.parse::<usize>() {
+                    index
+                } else {
+                    match args.iter().position(|a| a.name.as_deref() == Some(arg)) {
+                        Some(index) => index,
+                        None => {
+                            return Err(Error::new(
+                                fmt.span(),
+                                format_args!(
+                                    "there is no argument named `{}`, runtime values cannot be captured",
+                                    arg
+                                ),
+                            ));
+                        }
+                    }
+                };
+
+                let arg = match args.get_mut(index) {
+                    Some(arg) => arg,
+                    None => {
+                        return Err(Error::new(
+                            fmt.span(),
+                            format_args!("invalid reference to positional argument {}", index),
+                        ));
+                    }
+                };
+                arg.used = true;
+
+                let spec = parse_spec(spec).map_err(|msg| err(&msg))?;
+                write_value(&mut out, &arg.value, &spec)
+                    .map_err(|msg| Error::new_spanned(&arg.span_tokens, msg))?;
+            }
+            c => out.push(c),
+        }
+    }
+
+    Ok(out)
+}
+
+fn parse_spec(spec: &str) -> std::result::Result<Spec, String> {
+    let mut out = Spec::default();
+    let chars: Vec<char> = spec.chars().collect();
+    let mut i = 0;
+
+    let is_align = |c: char| matches!(c, '<' | '^' | '>');
+
+    // [[fill]align]
+    if chars.len() >= 2 && is_align(chars[1]) {
+        out.fill = Some(chars[0]);
+        out.align = Some(chars[1]);
+        i = 2;
+    } else if !chars.is_empty() && is_align(chars[0]) {
+        out.align = Some(chars[0]);
+        i = 1;
+    }
+
+    // [sign]
+    match chars.get(i) {
+        Some('+') => {
+            out.sign_plus = true;
+            i += 1;
+        }
+        Some('-') => i += 1,
+        _ => {}
+    }
+
+    // ['#']
+    if chars.get(i) == Some(&'#') {
+        out.alternate = true;
+        i += 1;
+    }
+
+    // ['0']
+    if chars.get(i) == Some(&'0') {
+        out.zero = true;
+        i += 1;
+    }
+
+    // [width]
+    let start = i;
+    while matches!(chars.get(i), Some(c) if c.is_ascii_digit()) {
+        i += 1;
+    }
+    if i > start {
+        let width: String = chars[start..i].iter().collect();
+        out.width = Some(width.parse().map_err(|_| "invalid format width")?);
+    }
+
+    // ['.' precision]
+    if chars.get(i) == Some(&'.') {
+        i += 1;
+        let start = i;
+        while matches!(chars.get(i), Some(c) if c.is_ascii_digit()) {
+            i += 1;
+        }
+        if i == start {
+            return Err("invalid format string: expected precision after `.`".to_owned());
+        }
+        let precision: String = chars[start..i].iter().collect();
+        out.precision = Some(precision.parse().map_err(|_| "invalid format precision")?);
+    }
+
+    // [type]
+    match &chars[i..] {
+        [] => {}
+        [ty @ ('?' | 'x' | 'X' | 'o' | 'b')] => out.ty = Some(*ty),
+        _ => {
+            return Err(format!(
+                "invalid format string: unsupported format spec `{}`",
+                spec
+            ));
+        }
+    }
+
+    Ok(out)
+}
+
+fn write_value(out: &mut String, value: &Value, spec: &Spec) -> std::result::Result<(), String> {
+    let numeric = matches!(value, Value::Int(_));
+
+    let (prefix, body) = match (value, spec.ty) {
+        (Value::Int(n), None | Some('?')) => {
+            let sign = if *n < 0 {
+                "-"
+            } else if spec.sign_plus {
+                "+"
+            } else {
+                ""
+            };
+            (sign.to_owned(), n.unsigned_abs().to_string())
+        }
+        (Value::Int(n), Some(ty)) => {
+            if *n < 0 {
+                return Err(format!(
+                    "cannot format a negative integer literal with `{}`",
+                    ty
+                ));
+            }
+
+            let (prefix, body) = match ty {
+                'x' => ("0x", format!("{:x}", n)),
+                'X' => ("0x", format!("{:X}", n)),
+                'o' => ("0o", format!("{:o}", n)),
+                _ => ("0b", format!("{:b}", n)),
+            };
+
+            let sign = if spec.sign_plus { "+" } else { "" };
+            let prefix = if spec.alternate { prefix } else { "" };
+            (format!("{}{}", sign, prefix), body)
+        }
+        (Value::Str(s), None) => (String::new(), truncate(s, spec.precision)),
+        (Value::Str(s), Some('?')) => (String::new(), format!("{:?}", s)),
+        (Value::Char(c), None) => (String::new(), truncate(&c.to_string(), spec.precision)),
+        (Value::Char(c), Some('?')) => (String::new(), format!("{:?}", c)),
+        (Value::Bool(b), None | Some('?')) => {
+            (String::new(), truncate(&b.to_string(), spec.precision))
+        }
+        (_, Some(ty)) => {
+            return Err(format!(
+                "`{}` formatting is only supported for integer literals",
+                ty
+            ));
+        }
+    };
+
+    let len = prefix.chars().count() + body.chars().count();
+    let padding = spec.width.map_or(0, |width| width.saturating_sub(len));
+
+    if spec.zero && numeric {
+        // Zero padding goes between the sign and the digits, ignoring alignment.
+        out.push_str(&prefix);
+        push_n(out, '0', padding);
+        out.push_str(&body);
+        return Ok(());
+    }
+
+    let fill = spec.fill.unwrap_or(' ');
+    let align = spec.align.unwrap_or(if numeric { '>' } else { '<' });
+    let (before, after) = match align {
+        '<' => (0, padding),
+        '^' => (padding / 2, padding - padding / 2),
+        _ => (padding, 0),
+    };
+
+    push_n(out, fill, before);
+    out.push_str(&prefix);
+    out.push_str(&body);
+    push_n(out, fill, after);
+
+    Ok(())
+}
+
+fn truncate(s: &str, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => s.chars().take(precision).collect(),
+        None => s.to_owned(),
+    }
+}
+
+fn push_n(out: &mut String, c: char, n: usize) {
+    for _ in 0..n {
+        out.push(c);
+    }
+}
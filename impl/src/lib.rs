@@ -3,15 +3,37 @@
 extern crate proc_macro;
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use proc_macro2::{Span, TokenStream};
-use syn::{Error, Result};
+use syn::{Error, LitStr, Result};
 
-use crate::parse::{IncludeInput, LitStrOrChar, WchInput, WchzInput};
+use crate::parse::{
+    BstrInput, CInput, CatalogInput, CountedInput, CpInput, DirInput, EnvInput, FormatConstInput,
+    IncludeInput, LinesInput, LitStrOrChar, MapInput, PatInput, PoolInput, StringTableInput,
+    WchInput, WchzInput,
+};
 
+mod bstr;
+mod catalog;
+mod cesu8;
+mod codepage;
+mod counted;
+mod derive;
+mod dir;
 mod encode;
+mod env;
+mod escape;
+mod format;
+mod lines;
+mod map;
+mod names;
 mod parse;
+mod pool;
+mod string_table;
+mod transform;
+mod utf7;
+mod widen;
 
 // Utility function to handle expanding syn errors into a TokenStream.
 fn expand_macro<F: FnOnce() -> Result<TokenStream>>(f: F) -> proc_macro::TokenStream {
@@ -23,20 +45,51 @@ fn expand_macro<F: FnOnce() -> Result<TokenStream>>(f: F) -> proc_macro::TokenSt
 
 #[proc_macro]
 pub fn wch(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let WchInput { ty, literal, .. } = syn::parse_macro_input!(input);
+    let WchInput {
+        ty,
+        transforms,
+        literal,
+        ..
+    } = syn::parse_macro_input!(input);
 
     expand_macro(|| match literal {
-        LitStrOrChar::Str(lit) => Ok(encode::expand_str(ty, &lit.value())),
-        LitStrOrChar::Char(lit) => encode::expand_char(ty, lit),
+        LitStrOrChar::Str(lit) if transforms.ill_formed.is_some() => {
+            escape::expand_units(ty, &lit.value(), lit.span(), false)
+        }
+        LitStrOrChar::Str(lit) => Ok(encode::expand_str(
+            ty,
+            &transform::apply(&transforms, lit.value(), lit.span(), Some(&lit.token()))?,
+        )),
+        LitStrOrChar::Char(_) if transforms.ill_formed.is_some() => Err(Error::new(
+            transforms.ill_formed.unwrap().span,
+            "the `ill_formed` option cannot be used with a character literal",
+        )),
+        LitStrOrChar::Char(lit) => {
+            encode::expand_char(ty, transform::apply_char(&transforms, lit)?)
+        }
     })
 }
 
 #[proc_macro]
 pub fn wchz(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let WchzInput { ty, literal, .. } = syn::parse_macro_input!(input);
+    let WchzInput {
+        ty,
+        transforms,
+        literal,
+        ..
+    } = syn::parse_macro_input!(input);
 
     expand_macro(|| {
-        let text = literal.value();
+        if transforms.ill_formed.is_some() {
+            return escape::expand_units(ty, &literal.value(), literal.span(), true);
+        }
+
+        let text = transform::apply(
+            &transforms,
+            literal.value(),
+            literal.span(),
+            Some(&literal.token()),
+        )?;
 
         if text.as_bytes().contains(&0) {
             return Err(Error::new(
@@ -49,12 +102,181 @@ pub fn wchz(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     })
 }
 
+#[proc_macro]
+pub fn wch_counted(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: CountedInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| counted::expand_counted(input))
+}
+
+#[proc_macro]
+pub fn wch_c(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let CInput { ty, literal, .. } = syn::parse_macro_input!(input);
+
+    expand_macro(|| escape::expand_c(ty, &literal, false))
+}
+
+#[proc_macro]
+pub fn wchz_c(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let CInput { ty, literal, .. } = syn::parse_macro_input!(input);
+
+    expand_macro(|| escape::expand_c(ty, &literal, true))
+}
+
+#[proc_macro]
+pub fn cp(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: CpInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| codepage::expand_cp(input, false))
+}
+
+#[proc_macro]
+pub fn cpz(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: CpInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| codepage::expand_cp(input, true))
+}
+
+#[proc_macro]
+pub fn cesu8(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let literal = syn::parse_macro_input!(input);
+
+    expand_macro(|| cesu8::expand_cesu8(literal, false, false))
+}
+
+#[proc_macro]
+pub fn cesu8z(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let literal = syn::parse_macro_input!(input);
+
+    expand_macro(|| cesu8::expand_cesu8(literal, false, true))
+}
+
+#[proc_macro]
+pub fn mutf8(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let literal = syn::parse_macro_input!(input);
+
+    expand_macro(|| cesu8::expand_cesu8(literal, true, false))
+}
+
+#[proc_macro]
+pub fn mutf8z(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let literal = syn::parse_macro_input!(input);
+
+    expand_macro(|| cesu8::expand_cesu8(literal, true, true))
+}
+
+#[proc_macro]
+pub fn utf7(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let literal = syn::parse_macro_input!(input);
+
+    expand_macro(|| utf7::expand_utf7(literal, false))
+}
+
+#[proc_macro]
+pub fn imap_utf7(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let literal = syn::parse_macro_input!(input);
+
+    expand_macro(|| utf7::expand_utf7(literal, true))
+}
+
+#[proc_macro]
+pub fn bstr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: BstrInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| bstr::expand_bstr(input))
+}
+
+#[proc_macro]
+pub fn wch_pat(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let PatInput {
+        ty, literal, rest, ..
+    } = syn::parse_macro_input!(input as PatInput<LitStrOrChar>);
+
+    expand_macro(|| match literal {
+        LitStrOrChar::Str(lit) => Ok(encode::expand_str_pat(ty, &lit.value(), rest.is_some())),
+        LitStrOrChar::Char(lit) => match rest {
+            Some((_, dots)) => Err(Error::new_spanned(
+                dots,
+                "rest pattern cannot follow a character",
+            )),
+            None => encode::expand_char(ty, lit),
+        },
+    })
+}
+
+#[proc_macro]
+pub fn wchz_pat(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let PatInput {
+        ty, literal, rest, ..
+    } = syn::parse_macro_input!(input as PatInput<LitStr>);
+
+    expand_macro(|| {
+        let text = literal.value();
+
+        if text.as_bytes().contains(&0) {
+            return Err(Error::new(
+                literal.span(),
+                "C-style string cannot contain nul characters",
+            ));
+        }
+
+        Ok(encode::expand_str_c_pat(ty, &text, rest.is_some()))
+    })
+}
+
+#[proc_macro]
+pub fn wformat_const(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: FormatConstInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| format::expand_format_const(input, false))
+}
+
+#[proc_macro]
+pub fn wformatz_const(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: FormatConstInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| format::expand_format_const(input, true))
+}
+
+#[proc_macro]
+pub fn env_wch(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: EnvInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| env::expand_env(input, false))
+}
+
+#[proc_macro]
+pub fn env_wchz(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: EnvInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| env::expand_env(input, true))
+}
+
+#[proc_macro]
+pub fn option_env_wch(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: EnvInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| env::expand_option_env(input))
+}
+
 #[proc_macro]
 pub fn include_wch(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let IncludeInput { ty, file_path, .. } = syn::parse_macro_input!(input);
+    let IncludeInput {
+        ty,
+        transforms,
+        file_path,
+        ..
+    } = syn::parse_macro_input!(input);
 
     expand_macro(|| {
+        codepage::reject_source(transforms.source)?;
+
         let text = read_file(&file_path)?;
+        if transforms.ill_formed.is_some() {
+            return escape::expand_units(ty, &text, file_path.span(), false);
+        }
+
+        let text = transform::apply(&transforms, text, file_path.span(), None)?;
 
         Ok(encode::expand_str(ty, &text))
     })
@@ -62,10 +284,22 @@ pub fn include_wch(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
 #[proc_macro]
 pub fn include_wchz(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let IncludeInput { ty, file_path, .. } = syn::parse_macro_input!(input);
+    let IncludeInput {
+        ty,
+        transforms,
+        file_path,
+        ..
+    } = syn::parse_macro_input!(input);
 
     expand_macro(|| {
+        codepage::reject_source(transforms.source)?;
+
         let text = read_file(&file_path)?;
+        if transforms.ill_formed.is_some() {
+            return escape::expand_units(ty, &text, file_path.span(), true);
+        }
+
+        let text = transform::apply(&transforms, text, file_path.span(), None)?;
 
         if text.as_bytes().contains(&0) {
             return Err(Error::new(
@@ -78,7 +312,101 @@ pub fn include_wchz(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     })
 }
 
+#[proc_macro]
+pub fn include_wch_lines(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: LinesInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| lines::expand_lines(input, false))
+}
+
+#[proc_macro]
+pub fn include_wchz_lines(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: LinesInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| lines::expand_lines(input, true))
+}
+
+#[proc_macro]
+pub fn include_wch_catalog(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: CatalogInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| catalog::expand_catalog(input))
+}
+
+#[proc_macro]
+pub fn include_wch_dir(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: DirInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| dir::expand_dir(input))
+}
+
+#[proc_macro]
+pub fn wch_pool(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: PoolInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| pool::expand_pool(input, false))
+}
+
+#[proc_macro]
+pub fn wchz_pool(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: PoolInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| pool::expand_pool(input, true))
+}
+
+#[proc_macro]
+pub fn wch_map(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: MapInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| map::expand_map(input))
+}
+
+#[proc_macro]
+pub fn string_table(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: StringTableInput = syn::parse_macro_input!(input);
+
+    expand_macro(|| string_table::expand_string_table(input))
+}
+
+#[proc_macro_derive(WideName, attributes(wide))]
+pub fn derive_wide_name(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input);
+
+    expand_macro(|| derive::expand_wide_name(input))
+}
+
+#[proc_macro_attribute]
+pub fn widen(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let ty = syn::parse_macro_input!(attr);
+    let item = syn::parse_macro_input!(item);
+
+    expand_macro(|| widen::expand_widen(ty, item))
+}
+
 fn read_file(path: &syn::LitStr) -> Result<String> {
+    read_path(&resolve_path(path)?, path.span())
+}
+
+// Returns the resolved path of a file given to a macro, to be passed to
+// `include_bytes!` so that Cargo rebuilds the crate when the file changes.
+fn tracked_path(path: &syn::LitStr) -> Result<String> {
+    let resolved = resolve_path(path)?;
+
+    match resolved.to_str() {
+        Some(tracked) => Ok(tracked.to_owned()),
+        None => Err(Error::new(
+            path.span(),
+            format_args!("path {} is not valid UTF-8", resolved.display()),
+        )),
+    }
+}
+
+// Resolves a path given to a macro, relative to the directory of the calling
+// source file.
+fn resolve_path(path: &syn::LitStr) -> Result<PathBuf> {
     let span = path.span();
     let mut path = PathBuf::from(path.value());
 
@@ -92,7 +420,11 @@ fn read_file(path: &syn::LitStr) -> Result<String> {
         path = dir;
     }
 
-    match fs::read_to_string(&path) {
+    Ok(path)
+}
+
+fn read_path(path: &Path, span: Span) -> Result<String> {
+    match fs::read_to_string(path) {
         Ok(text) => Ok(text),
         Err(err) => Err(Error::new(
             span,
@@ -0,0 +1,69 @@
+use proc_macro2::TokenStream;
+use syn::{Error, Result};
+
+use crate::encode::Encode;
+use crate::parse::{LinesInput, WCharType};
+
+pub fn expand_lines(input: LinesInput, nul_terminated: bool) -> Result<TokenStream> {
+    fn quote_lines<T: Encode>(input: LinesInput, nul_terminated: bool) -> Result<TokenStream> {
+        let LinesInput {
+            options, file_path, ..
+        } = input;
+
+        let text = crate::read_file(&file_path)?;
+        let tracked = crate::tracked_path(&file_path)?;
+        let comment_prefix = options.comment_prefix.map(|prefix| prefix.value());
+
+        let ty = T::wchar_type();
+        let mut lines = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let trimmed = line.trim_start();
+
+            if options.skip_blank && trimmed.is_empty() {
+                continue;
+            }
+            if let Some(prefix) = &comment_prefix {
+                if trimmed.starts_with(prefix.as_str()) {
+                    continue;
+                }
+            }
+
+            let chars = if nul_terminated {
+                if line.as_bytes().contains(&0) {
+                    return Err(Error::new(
+                        file_path.span(),
+                        format_args!(
+                            "{}:{}: C-style string cannot contain nul characters",
+                            file_path.value(),
+                            index + 1
+                        ),
+                    ));
+                }
+
+                T::encode_str_c(line)
+            } else {
+                T::encode_str(line)
+            };
+
+            lines.push(quote::quote! { &[#(#chars),*] as &[#ty] });
+        }
+
+        // Including the file as bytes makes Cargo rebuild the crate when it
+        // changes.
+        Ok(quote::quote! {
+            {
+                const _: &[u8] = ::core::include_bytes!(#tracked);
+
+                &[#(#lines),*] as &[&[#ty]]
+            }
+        })
+    }
+
+    match input.ty {
+        WCharType::U16(_) => quote_lines::<u16>(input, nul_terminated),
+        WCharType::U32(_) => quote_lines::<u32>(input, nul_terminated),
+        WCharType::I16(_) => quote_lines::<i16>(input, nul_terminated),
+        WCharType::I32(_) => quote_lines::<i32>(input, nul_terminated),
+    }
+}
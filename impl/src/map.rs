@@ -0,0 +1,63 @@
+use proc_macro2::TokenStream;
+use syn::{Error, Result};
+
+use crate::encode::Encode;
+use crate::parse::{MapEntry, MapInput, WCharType};
+
+pub fn expand_map(input: MapInput) -> Result<TokenStream> {
+    fn quote_map<T: Encode>(input: MapInput) -> Result<TokenStream> {
+        let MapInput {
+            krate,
+            ignore_ascii_case,
+            entries,
+            ..
+        } = input;
+
+        let ignore_ascii_case = ignore_ascii_case.is_some();
+
+        // Pair each entry with the key it is ordered by, which is case folded if
+        // the map ignores ASCII case.
+        let mut entries: Vec<(Vec<T>, MapEntry)> = entries
+            .into_iter()
+            .map(|entry| {
+                let mut key = entry.key.value();
+                if ignore_ascii_case {
+                    key.make_ascii_lowercase();
+                }
+
+                (T::encode_str(&key), entry)
+            })
+            .collect();
+
+        // The lookup performs a binary search, so the entries must be sorted.
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        for pair in entries.windows(2) {
+            if pair[0].0 == pair[1].0 {
+                return Err(Error::new(
+                    pair[1].1.key.span(),
+                    "duplicate key in wide string map",
+                ));
+            }
+        }
+
+        let ty = T::wchar_type();
+        let entries = entries.into_iter().map(|(_, entry)| {
+            let key = T::encode_str(&entry.key.value());
+            let value = entry.value;
+
+            quote::quote! { (&[#(#key),*] as &[#ty], #value) }
+        });
+
+        Ok(quote::quote! {
+            #krate::WStrMap::from_sorted_entries(&[#(#entries),*], #ignore_ascii_case)
+        })
+    }
+
+    match input.ty {
+        WCharType::U16(_) => quote_map::<u16>(input),
+        WCharType::U32(_) => quote_map::<u32>(input),
+        WCharType::I16(_) => quote_map::<i16>(input),
+        WCharType::I32(_) => quote_map::<i32>(input),
+    }
+}
@@ -0,0 +1,159 @@
+use proc_macro2::{Literal, Span};
+use syn::{Error, Result};
+
+// The length in bytes of the longest Unicode character name, that of U+1FBA9
+// BOX DRAWINGS LIGHT DIAGONAL UPPER CENTRE TO MIDDLE RIGHT AND MIDDLE LEFT TO
+// LOWER CENTRE in UnicodeData.txt, which is also the `MAX_NAME_LENGTH` of the
+// tables generated by `unicode_names2`.
+#[cfg(feature = "unicode-names")]
+const MAX_NAME_LENGTH: usize = 88;
+
+// Resolves `\N{NAME}` escapes in the text to the named characters.
+//
+// A backslash escapes the one that follows it when they precede `N{`, so that
+// `\\N{` is kept as a literal `\N{`.
+//
+// The text is the unescaped value of the literal, if any, so that other escapes
+// are resolved by the compiler as usual. Errors point at the offending escape
+// within the literal where the compiler supports it, or else at the span.
+pub fn resolve(text: &str, span: Span, literal: Option<&Literal>) -> Result<String> {
+    let error_span = |start: usize, end: usize| {
+        literal
+            .and_then(|literal| escape_span(literal, start, end))
+            .unwrap_or(span)
+    };
+
+    let mut out = String::with_capacity(text.len());
+
+    let mut rest = text;
+    while let Some(start) = rest.find("N{") {
+        let backslashes = rest[..start]
+            .bytes()
+            .rev()
+            .take_while(|&b| b == b'\\')
+            .count();
+
+        // Each pair of backslashes is kept as one.
+        out.push_str(&rest[..start - backslashes + backslashes / 2]);
+        let escape = text.len() - rest.len() + start;
+        rest = &rest[start + 2..];
+
+        if backslashes % 2 == 0 {
+            out.push_str("N{");
+            continue;
+        }
+
+        // Include the backslash that starts the escape.
+        let escape = escape - 1;
+
+        let end = match rest.find('}') {
+            Some(end) => end,
+            None => {
+                return Err(Error::new(
+                    error_span(escape, text.len()),
+                    format_args!("unterminated `\\N{{...}}` escape `\\N{{{}`", rest),
+                ));
+            }
+        };
+
+        let name = &rest[..end];
+        rest = &rest[end + 1..];
+
+        match lookup(name) {
+            Some(c) => out.push(c),
+            None => {
+                return Err(Error::new(
+                    error_span(escape, text.len() - rest.len()),
+                    format_args!("unknown character name `{}`", name),
+                ));
+            }
+        }
+    }
+    out.push_str(rest);
+
+    Ok(out)
+}
+
+// Returns the span of the source of the literal that unescapes to the given
+// range of its value, which is only available on nightly.
+fn escape_span(literal: &Literal, start: usize, end: usize) -> Option<Span> {
+    let offsets = source_offsets(&literal.to_string())?;
+
+    literal.subspan(*offsets.get(start)?..*offsets.get(end)?)
+}
+
+// Maps each byte offset in the value of a string or character literal, and the
+// end of the value, to the offset in the source it was unescaped from.
+fn source_offsets(source: &str) -> Option<Vec<usize>> {
+    let mut offsets = Vec::with_capacity(source.len());
+
+    // Raw strings have no escapes, so only the prefix needs to be skipped.
+    if let Some(raw) = source.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let prefix = 1 + hashes + 1;
+        offsets.extend(prefix..source.len() - hashes);
+        return Some(offsets);
+    }
+
+    let quote = source.chars().next()?;
+    if quote != '"' && quote != '\'' {
+        return None;
+    }
+
+    let content = &source[1..source.len() - 1];
+    let mut chars = content.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let len = if c != '\\' {
+            c.len_utf8()
+        } else {
+            match chars.next()?.1 {
+                // A line continuation skips the newline and the whitespace
+                // that follows, producing nothing.
+                '\n' => {
+                    while let Some(&(_, ' ' | '\t' | '\n' | '\r')) = chars.peek() {
+                        chars.next();
+                    }
+                    0
+                }
+                'x' => {
+                    chars.next()?;
+                    chars.next()?;
+                    1
+                }
+                'u' => {
+                    let mut hex = String::new();
+                    for (_, c) in chars.by_ref() {
+                        match c {
+                            '{' | '_' => {}
+                            '}' => break,
+                            c => hex.push(c),
+                        }
+                    }
+                    char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?.len_utf8()
+                }
+                _ => 1,
+            }
+        };
+
+        offsets.resize(offsets.len() + len, 1 + i);
+    }
+    offsets.push(source.len() - 1);
+
+    Some(offsets)
+}
+
+#[cfg(feature = "unicode-names")]
+fn lookup(name: &str) -> Option<char> {
+    // The lookup panics on names much longer than any character name.
+    if name.len() > MAX_NAME_LENGTH {
+        return None;
+    }
+
+    unicode_names2::character(name)
+}
+
+// The `names` option is rejected when parsed without the feature enabled.
+#[cfg(not(feature = "unicode-names"))]
+fn lookup(_name: &str) -> Option<char> {
+    None
+}
@@ -1,15 +1,48 @@
-use proc_macro2::TokenStream;
+use std::env;
+
+use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream, Result};
-use syn::{LitChar, LitStr, Token};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Error, Expr, ExprLit, Ident, Lit, LitByte, LitByteStr, LitChar, LitInt, LitStr,
+    Macro, Token, Visibility, braced,
+};
+
+use crate::codepage;
 
 mod kw {
     syn::custom_keyword!(u16);
     syn::custom_keyword!(u32);
     syn::custom_keyword!(i16);
     syn::custom_keyword!(i32);
+
+    syn::custom_keyword!(u8);
+    syn::custom_keyword!(len);
+
+    syn::custom_keyword!(ignore_ascii_case);
+    syn::custom_keyword!(reference);
+    syn::custom_keyword!(glob);
+    syn::custom_keyword!(skip_blank);
+    syn::custom_keyword!(skip_comments);
+
+    syn::custom_keyword!(latin1);
+    syn::custom_keyword!(cp1252);
+
+    syn::custom_keyword!(ill_formed);
+    syn::custom_keyword!(names);
+    syn::custom_keyword!(nfc);
+    syn::custom_keyword!(nfd);
+    syn::custom_keyword!(nfkc);
+    syn::custom_keyword!(nfkd);
+    syn::custom_keyword!(upper);
+    syn::custom_keyword!(lower);
+    syn::custom_keyword!(dedent);
 }
 
+#[derive(Clone, Copy)]
 pub enum WCharType {
     U16(kw::u16),
     U32(kw::u32),
@@ -63,41 +96,474 @@ impl Parse for LitStrOrChar {
     }
 }
 
+// Parses a string or character literal, where byte literals are decoded into
+// the equivalent string or character literal with the source encoding.
+fn parse_literal(
+    input: ParseStream,
+    source: Option<(SourceEncoding, Span)>,
+) -> Result<LitStrOrChar> {
+    let lookahead = input.lookahead1();
+    if lookahead.peek(LitStr) || lookahead.peek(LitChar) {
+        codepage::reject_source(source)?;
+        input.parse()
+    } else if lookahead.peek(LitByteStr) {
+        let lit = codepage::decode_byte_str(source, &input.parse()?)?;
+        Ok(LitStrOrChar::Str(lit))
+    } else if lookahead.peek(LitByte) {
+        let lit = codepage::decode_byte(source, &input.parse()?)?;
+        Ok(LitStrOrChar::Char(lit))
+    } else {
+        Err(lookahead.error())
+    }
+}
+
+// Parses a string literal, where a byte string literal is decoded into the
+// equivalent string literal with the source encoding.
+fn parse_str_literal(input: ParseStream, source: Option<(SourceEncoding, Span)>) -> Result<LitStr> {
+    let lookahead = input.lookahead1();
+    if lookahead.peek(LitStr) {
+        codepage::reject_source(source)?;
+        input.parse()
+    } else if lookahead.peek(LitByteStr) {
+        codepage::decode_byte_str(source, &input.parse()?)
+    } else {
+        Err(lookahead.error())
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum SourceEncoding {
+    Latin1,
+    Cp1252,
+}
+
+#[derive(Clone, Copy)]
+pub enum Case {
+    Upper,
+    Lower,
+}
+
+#[derive(Clone, Copy)]
+pub enum Normalization {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+// Text transforms given before the literal, e.g. `wch!(u16, nfc, upper, "...")`.
+//
+// The `ill_formed` option enables code unit escapes, and can't be combined with
+// a source encoding or the other transforms.
+#[derive(Default)]
+pub struct Transforms {
+    pub ill_formed: Option<kw::ill_formed>,
+    pub source: Option<(SourceEncoding, Span)>,
+    pub names: Option<kw::names>,
+    pub dedent: bool,
+    pub case: Option<Case>,
+    pub normalization: Option<Normalization>,
+}
+
+impl Parse for Transforms {
+    fn parse(input: ParseStream) -> Result<Self> {
+        fn set<T>(slot: &mut Option<T>, value: T, span: Span, what: &str) -> Result<()> {
+            if slot.is_some() {
+                return Err(Error::new(
+                    span,
+                    format_args!("only one {} can be applied", what),
+                ));
+            }
+            *slot = Some(value);
+            Ok(())
+        }
+
+        let mut transforms = Transforms::default();
+
+        loop {
+            let span = input.span();
+            let lookahead = input.lookahead1();
+
+            if lookahead.peek(kw::u16)
+                || lookahead.peek(kw::u32)
+                || lookahead.peek(kw::i16)
+                || lookahead.peek(kw::i32)
+            {
+                return Err(Error::new(
+                    span,
+                    "the character type must be the first argument",
+                ));
+            } else if lookahead.peek(kw::latin1) {
+                input.parse::<kw::latin1>()?;
+                let source = (SourceEncoding::Latin1, span);
+                set(&mut transforms.source, source, span, "source encoding")?;
+            } else if lookahead.peek(kw::cp1252) {
+                input.parse::<kw::cp1252>()?;
+                let source = (SourceEncoding::Cp1252, span);
+                set(&mut transforms.source, source, span, "source encoding")?;
+            } else if lookahead.peek(kw::ill_formed) {
+                let ill_formed = input.parse::<kw::ill_formed>()?;
+                if transforms.ill_formed.is_some() {
+                    return Err(Error::new(span, "duplicate `ill_formed` option"));
+                }
+                transforms.ill_formed = Some(ill_formed);
+            } else if lookahead.peek(kw::names) {
+                let names = input.parse::<kw::names>()?;
+                if cfg!(not(feature = "unicode-names")) {
+                    return Err(Error::new(
+                        span,
+                        "the `names` option requires the `unicode-names` feature",
+                    ));
+                }
+                if transforms.names.is_some() {
+                    return Err(Error::new(span, "duplicate `names` option"));
+                }
+                transforms.names = Some(names);
+            } else if lookahead.peek(kw::dedent) {
+                input.parse::<kw::dedent>()?;
+                if transforms.dedent {
+                    return Err(Error::new(span, "duplicate `dedent` transform"));
+                }
+                transforms.dedent = true;
+            } else if lookahead.peek(kw::upper) {
+                input.parse::<kw::upper>()?;
+                set(&mut transforms.case, Case::Upper, span, "case mapping")?;
+            } else if lookahead.peek(kw::lower) {
+                input.parse::<kw::lower>()?;
+                set(&mut transforms.case, Case::Lower, span, "case mapping")?;
+            } else if lookahead.peek(kw::nfc) {
+                input.parse::<kw::nfc>()?;
+                set(
+                    &mut transforms.normalization,
+                    Normalization::Nfc,
+                    span,
+                    "normalization form",
+                )?;
+            } else if lookahead.peek(kw::nfd) {
+                input.parse::<kw::nfd>()?;
+                set(
+                    &mut transforms.normalization,
+                    Normalization::Nfd,
+                    span,
+                    "normalization form",
+                )?;
+            } else if lookahead.peek(kw::nfkc) {
+                input.parse::<kw::nfkc>()?;
+                set(
+                    &mut transforms.normalization,
+                    Normalization::Nfkc,
+                    span,
+                    "normalization form",
+                )?;
+            } else if lookahead.peek(kw::nfkd) {
+                input.parse::<kw::nfkd>()?;
+                set(
+                    &mut transforms.normalization,
+                    Normalization::Nfkd,
+                    span,
+                    "normalization form",
+                )?;
+            } else if lookahead.peek(Lit) {
+                if let Some(ill_formed) = transforms.ill_formed {
+                    if transforms.source.is_some() {
+                        return Err(Error::new(
+                            ill_formed.span,
+                            "the `ill_formed` option cannot be combined with a source encoding",
+                        ));
+                    }
+                    if transforms.names.is_some()
+                        || transforms.dedent
+                        || transforms.case.is_some()
+                        || transforms.normalization.is_some()
+                    {
+                        return Err(Error::new(
+                            ill_formed.span,
+                            "the `ill_formed` option cannot be combined with text transforms",
+                        ));
+                    }
+                }
+                return Ok(transforms);
+            } else {
+                return Err(lookahead.error());
+            }
+
+            input.parse::<Token![,]>()?;
+        }
+    }
+}
+
 pub struct WchInput {
     pub ty: WCharType,
-    pub comma: Token![,],
+    pub _comma: Token![,],
+    pub transforms: Transforms,
     pub literal: LitStrOrChar,
 }
 
 impl Parse for WchInput {
     fn parse(input: ParseStream) -> Result<Self> {
+        let ty = input.parse()?;
+        let _comma = input.parse()?;
+        let transforms: Transforms = input.parse()?;
+        let literal = parse_literal(input, transforms.source)?;
+
         Ok(WchInput {
-            ty: input.parse()?,
-            comma: input.parse()?,
-            literal: input.parse()?,
+            ty,
+            _comma,
+            transforms,
+            literal,
         })
     }
 }
 
 pub struct WchzInput {
     pub ty: WCharType,
-    pub comma: Token![,],
+    pub _comma: Token![,],
+    pub transforms: Transforms,
     pub literal: LitStr,
 }
 
 impl Parse for WchzInput {
     fn parse(input: ParseStream) -> Result<Self> {
+        let ty = input.parse()?;
+        let _comma = input.parse()?;
+        let transforms: Transforms = input.parse()?;
+        let literal = parse_str_literal(input, transforms.source)?;
+
         Ok(WchzInput {
+            ty,
+            _comma,
+            transforms,
+            literal,
+        })
+    }
+}
+
+pub struct CInput {
+    pub ty: WCharType,
+    pub _comma: Token![,],
+    pub literal: LitStr,
+}
+
+impl Parse for CInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(CInput {
             ty: input.parse()?,
-            comma: input.parse()?,
+            _comma: input.parse()?,
+            literal: input.parse()?,
+        })
+    }
+}
+
+pub struct CpInput {
+    pub page: LitInt,
+    pub _comma: Token![,],
+    pub literal: LitStr,
+}
+
+impl Parse for CpInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(CpInput {
+            page: input.parse()?,
+            _comma: input.parse()?,
             literal: input.parse()?,
         })
     }
 }
 
+// The type of the length prefix of a counted string.
+pub enum CountType {
+    U8(kw::u8),
+    U16(kw::u16),
+    U32(kw::u32),
+}
+
+impl CountType {
+    pub fn bits(&self) -> u32 {
+        match self {
+            CountType::U8(_) => 8,
+            CountType::U16(_) => 16,
+            CountType::U32(_) => 32,
+        }
+    }
+}
+
+impl Parse for CountType {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::u8) {
+            Ok(CountType::U8(input.parse()?))
+        } else if lookahead.peek(kw::u16) {
+            Ok(CountType::U16(input.parse()?))
+        } else if lookahead.peek(kw::u32) {
+            Ok(CountType::U32(input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl ToTokens for CountType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            CountType::U8(ty) => ty.to_tokens(tokens),
+            CountType::U16(ty) => ty.to_tokens(tokens),
+            CountType::U32(ty) => ty.to_tokens(tokens),
+        }
+    }
+}
+
+pub struct CountedInput {
+    pub ty: WCharType,
+    pub _comma1: Token![,],
+    pub _len: kw::len,
+    pub _eq: Token![=],
+    pub count_ty: CountType,
+    pub _comma2: Token![,],
+    pub literal: LitStr,
+}
+
+impl Parse for CountedInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(CountedInput {
+            ty: input.parse()?,
+            _comma1: input.parse()?,
+            _len: input.parse()?,
+            _eq: input.parse()?,
+            count_ty: input.parse()?,
+            _comma2: input.parse()?,
+            literal: input.parse()?,
+        })
+    }
+}
+
+pub struct BstrInput {
+    pub krate: Ident,
+    pub _comma: Token![,],
+    pub literal: LitStr,
+}
+
+impl Parse for BstrInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(BstrInput {
+            // The `$crate` path of the calling crate, passed by the wrapper macro.
+            krate: input.call(Ident::parse_any)?,
+            _comma: input.parse()?,
+            literal: input.parse()?,
+        })
+    }
+}
+
+pub struct PatInput<L> {
+    pub ty: WCharType,
+    pub _comma: Token![,],
+    pub literal: L,
+    pub rest: Option<(Token![,], Token![..])>,
+}
+
+impl<L: Parse> Parse for PatInput<L> {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(PatInput {
+            ty: input.parse()?,
+            _comma: input.parse()?,
+            literal: input.parse()?,
+            rest: if input.is_empty() {
+                None
+            } else {
+                Some((input.parse()?, input.parse()?))
+            },
+        })
+    }
+}
+
+pub struct EnvInput {
+    pub ty: WCharType,
+    pub _comma: Token![,],
+    pub name: LitStr,
+    pub _trailing_comma: Option<Token![,]>,
+}
+
+impl Parse for EnvInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(EnvInput {
+            ty: input.parse()?,
+            _comma: input.parse()?,
+            name: input.parse()?,
+            _trailing_comma: input.parse()?,
+        })
+    }
+}
+
+// Returns `true` if the input starts with a macro invocation, such as a file
+// path given by `concat!`.
+fn peek_macro(input: ParseStream) -> bool {
+    input.peek(Ident) && input.peek2(Token![!])
+}
+
+// Parses a file path, either a string literal or built from string literals
+// and `env!("CARGO_MANIFEST_DIR")` with `concat!`, as is common with
+// `include_str!`. The latter allows paths within the package to be used without
+// the `unstable` feature, which relative paths require.
+pub fn parse_file_path(input: ParseStream) -> Result<LitStr> {
+    fn eval(mac: &Macro, path: &mut String) -> Result<()> {
+        if mac.path.is_ident("env") {
+            let key: LitStr = mac.parse_body()?;
+            if key.value() != "CARGO_MANIFEST_DIR" {
+                return Err(Error::new(
+                    key.span(),
+                    "only `CARGO_MANIFEST_DIR` can be read in a file path",
+                ));
+            }
+
+            match env::var("CARGO_MANIFEST_DIR") {
+                Ok(dir) => path.push_str(&dir),
+                Err(_) => {
+                    return Err(Error::new(
+                        key.span(),
+                        "environment variable `CARGO_MANIFEST_DIR` not defined at compile time",
+                    ));
+                }
+            }
+        } else if mac.path.is_ident("concat") {
+            let args = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+            for arg in args {
+                match arg {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit), ..
+                    }) => path.push_str(&lit.value()),
+                    Expr::Macro(arg) => eval(&arg.mac, path)?,
+                    arg => {
+                        return Err(Error::new_spanned(
+                            arg,
+                            "expected a string literal or `env!(\"CARGO_MANIFEST_DIR\")`",
+                        ));
+                    }
+                }
+            }
+        } else {
+            return Err(Error::new_spanned(
+                &mac.path,
+                "expected a string literal, `concat!` or `env!`",
+            ));
+        }
+
+        Ok(())
+    }
+
+    if peek_macro(input) {
+        let mac: Macro = input.parse()?;
+        let mut path = String::new();
+        eval(&mac, &mut path)?;
+
+        Ok(LitStr::new(&path, mac.path.span()))
+    } else {
+        input.parse()
+    }
+}
+
 pub struct IncludeInput {
     pub ty: WCharType,
-    pub comma: Token![,],
+    pub _comma: Token![,],
+    pub transforms: Transforms,
     pub file_path: LitStr,
 }
 
@@ -105,8 +571,330 @@ impl Parse for IncludeInput {
     fn parse(input: ParseStream) -> Result<Self> {
         Ok(IncludeInput {
             ty: input.parse()?,
-            comma: input.parse()?,
+            _comma: input.parse()?,
+            transforms: input.parse()?,
             file_path: input.parse()?,
         })
     }
 }
+
+pub struct LinesInput {
+    pub ty: WCharType,
+    pub _comma: Token![,],
+    pub options: LineOptions,
+    pub file_path: LitStr,
+}
+
+impl Parse for LinesInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(LinesInput {
+            ty: input.parse()?,
+            _comma: input.parse()?,
+            options: input.parse()?,
+            file_path: input.call(parse_file_path)?,
+        })
+    }
+}
+
+// The options of the line-split includes, given between the character type and
+// the file path.
+#[derive(Default)]
+pub struct LineOptions {
+    pub skip_blank: bool,
+    pub comment_prefix: Option<LitStr>,
+}
+
+impl Parse for LineOptions {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut options = LineOptions::default();
+
+        loop {
+            let span = input.span();
+            let lookahead = input.lookahead1();
+
+            if lookahead.peek(kw::skip_blank) {
+                input.parse::<kw::skip_blank>()?;
+                if options.skip_blank {
+                    return Err(Error::new(span, "duplicate `skip_blank` option"));
+                }
+                options.skip_blank = true;
+            } else if lookahead.peek(kw::skip_comments) {
+                input.parse::<kw::skip_comments>()?;
+                input.parse::<Token![=]>()?;
+                let prefix: LitStr = input.parse()?;
+
+                if options.comment_prefix.is_some() {
+                    return Err(Error::new(span, "duplicate `skip_comments` option"));
+                }
+                if prefix.value().is_empty() {
+                    return Err(Error::new(prefix.span(), "comment prefix cannot be empty"));
+                }
+                options.comment_prefix = Some(prefix);
+            } else if lookahead.peek(LitStr) || peek_macro(input) {
+                return Ok(options);
+            } else {
+                return Err(lookahead.error());
+            }
+
+            input.parse::<Token![,]>()?;
+        }
+    }
+}
+
+pub struct CatalogInput {
+    pub ty: WCharType,
+    pub _comma1: Token![,],
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub _mod_token: Token![mod],
+    pub name: Ident,
+    pub _comma2: Token![,],
+    pub file_path: LitStr,
+    pub reference: Option<(Token![,], kw::reference, Token![=], LitStr)>,
+}
+
+impl Parse for CatalogInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let catalog = CatalogInput {
+            ty: input.parse()?,
+            _comma1: input.parse()?,
+            attrs: input.call(Attribute::parse_outer)?,
+            vis: input.parse()?,
+            _mod_token: input.parse()?,
+            name: input.parse()?,
+            _comma2: input.parse()?,
+            file_path: input.call(parse_file_path)?,
+            reference: if input.peek(Token![,]) && input.peek2(kw::reference) {
+                Some((
+                    input.parse()?,
+                    input.parse()?,
+                    input.parse()?,
+                    input.call(parse_file_path)?,
+                ))
+            } else {
+                None
+            },
+        };
+
+        // Allow a trailing comma.
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(catalog)
+    }
+}
+
+pub struct DirInput {
+    pub ty: WCharType,
+    pub _comma1: Token![,],
+    pub krate: Ident,
+    pub _comma2: Token![,],
+    pub dir_path: LitStr,
+    pub glob: Option<(Token![,], kw::glob, Token![=], LitStr)>,
+}
+
+impl Parse for DirInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let dir = DirInput {
+            ty: input.parse()?,
+            _comma1: input.parse()?,
+            // The `$crate` path of the calling crate, passed by the wrapper macro.
+            krate: input.call(Ident::parse_any)?,
+            _comma2: input.parse()?,
+            dir_path: input.call(parse_file_path)?,
+            glob: if input.peek(Token![,]) && input.peek2(kw::glob) {
+                Some((
+                    input.parse()?,
+                    input.parse()?,
+                    input.parse()?,
+                    input.parse()?,
+                ))
+            } else {
+                None
+            },
+        };
+
+        // Allow a trailing comma.
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(dir)
+    }
+}
+
+pub struct PoolInput {
+    pub ty: WCharType,
+    pub _comma1: Token![,],
+    pub krate: Ident,
+    pub _comma2: Token![,],
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub _mod_token: Token![mod],
+    pub name: Ident,
+    pub _brace: syn::token::Brace,
+    pub entries: Punctuated<PoolEntry, Token![,]>,
+}
+
+impl Parse for PoolInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(PoolInput {
+            ty: input.parse()?,
+            _comma1: input.parse()?,
+            // The `$crate` path of the calling crate, passed by the wrapper macro.
+            krate: input.call(Ident::parse_any)?,
+            _comma2: input.parse()?,
+            attrs: input.call(Attribute::parse_outer)?,
+            vis: input.parse()?,
+            _mod_token: input.parse()?,
+            name: input.parse()?,
+            _brace: braced!(content in input),
+            entries: content.parse_terminated(PoolEntry::parse)?,
+        })
+    }
+}
+
+pub struct PoolEntry {
+    pub attrs: Vec<Attribute>,
+    pub name: Ident,
+    pub _eq: Token![=],
+    pub literal: LitStr,
+}
+
+impl Parse for PoolEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(PoolEntry {
+            attrs: input.call(Attribute::parse_outer)?,
+            name: input.parse()?,
+            _eq: input.parse()?,
+            literal: input.parse()?,
+        })
+    }
+}
+
+pub struct MapInput {
+    pub ty: WCharType,
+    pub _comma1: Token![,],
+    pub krate: Ident,
+    pub _comma2: Token![,],
+    pub ignore_ascii_case: Option<(kw::ignore_ascii_case, Token![,])>,
+    pub entries: Punctuated<MapEntry, Token![,]>,
+}
+
+impl Parse for MapInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(MapInput {
+            ty: input.parse()?,
+            _comma1: input.parse()?,
+            // The `$crate` path of the calling crate, passed by the wrapper macro.
+            krate: input.call(Ident::parse_any)?,
+            _comma2: input.parse()?,
+            ignore_ascii_case: if input.peek(kw::ignore_ascii_case) {
+                Some((input.parse()?, input.parse()?))
+            } else {
+                None
+            },
+            entries: input.parse_terminated(MapEntry::parse)?,
+        })
+    }
+}
+
+pub struct MapEntry {
+    pub key: LitStr,
+    pub _arrow: Token![=>],
+    pub value: Expr,
+}
+
+impl Parse for MapEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(MapEntry {
+            key: input.parse()?,
+            _arrow: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
+
+pub struct StringTableInput {
+    pub krate: Ident,
+    pub _comma: Token![,],
+    pub entries: Punctuated<StringTableEntry, Token![,]>,
+}
+
+impl Parse for StringTableInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(StringTableInput {
+            // The `$crate` path of the calling crate, passed by the wrapper macro.
+            krate: input.call(Ident::parse_any)?,
+            _comma: input.parse()?,
+            entries: input.parse_terminated(StringTableEntry::parse)?,
+        })
+    }
+}
+
+pub struct StringTableEntry {
+    pub id: LitInt,
+    pub _arrow: Token![=>],
+    pub literal: LitStr,
+}
+
+impl Parse for StringTableEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(StringTableEntry {
+            id: input.parse()?,
+            _arrow: input.parse()?,
+            literal: input.parse()?,
+        })
+    }
+}
+
+pub struct FormatConstInput {
+    pub ty: WCharType,
+    pub _comma: Token![,],
+    pub fmt: LitStr,
+    pub args: Punctuated<FormatArg, Token![,]>,
+}
+
+impl Parse for FormatConstInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ty = input.parse()?;
+        let _comma = input.parse()?;
+        let fmt = input.parse()?;
+
+        let args = if input.is_empty() {
+            Punctuated::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            input.parse_terminated(FormatArg::parse)?
+        };
+
+        Ok(FormatConstInput {
+            ty,
+            _comma,
+            fmt,
+            args,
+        })
+    }
+}
+
+pub struct FormatArg {
+    pub name: Option<(Ident, Token![=])>,
+    pub expr: Expr,
+}
+
+impl Parse for FormatArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+            Some((input.parse()?, input.parse()?))
+        } else {
+            None
+        };
+
+        Ok(FormatArg {
+            name,
+            expr: input.parse()?,
+        })
+    }
+}
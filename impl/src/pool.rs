@@ -0,0 +1,96 @@
+use proc_macro2::TokenStream;
+use syn::{Error, Result};
+
+use crate::encode::Encode;
+use crate::parse::{PoolEntry, PoolInput, WCharType};
+
+pub fn expand_pool(input: PoolInput, nul_terminated: bool) -> Result<TokenStream> {
+    fn quote_pool<T: Encode>(input: PoolInput, nul_terminated: bool) -> Result<TokenStream> {
+        let PoolInput {
+            krate,
+            attrs,
+            vis,
+            name,
+            entries,
+            ..
+        } = input;
+
+        let mut buffer = Vec::new();
+        let mut offsets = vec![0usize];
+        let mut ids = Vec::with_capacity(entries.len());
+        let mut ranges = Vec::with_capacity(entries.len());
+
+        for (id, entry) in entries.into_iter().enumerate() {
+            let PoolEntry {
+                attrs,
+                name,
+                literal,
+                ..
+            } = entry;
+
+            // The module also contains the buffer and pool statics.
+            if name == "BUFFER" || name == "POOL" {
+                return Err(Error::new(
+                    name.span(),
+                    format_args!("`{}` is reserved for the pool itself", name),
+                ));
+            }
+
+            let text = literal.value();
+            let offset = buffer.len();
+
+            if nul_terminated {
+                if text.as_bytes().contains(&0) {
+                    return Err(Error::new(
+                        literal.span(),
+                        "C-style string cannot contain nul characters",
+                    ));
+                }
+
+                buffer.extend(T::encode_str_c(&text));
+            } else {
+                buffer.extend(T::encode_str(&text));
+            }
+            offsets.push(buffer.len());
+
+            let len = buffer.len() - offset;
+            ranges.push(quote::quote! {
+                #(#attrs)*
+                pub const #name: (usize, usize) = (#offset, #len);
+            });
+            ids.push(quote::quote! {
+                #(#attrs)*
+                pub const #name: usize = #id;
+            });
+        }
+
+        let ty = T::wchar_type();
+        let len = buffer.len();
+
+        Ok(quote::quote! {
+            #(#attrs)*
+            #vis mod #name {
+                #(#ids)*
+
+                /// The offset and length of each string within `BUFFER`, by name.
+                pub mod ranges {
+                    #(#ranges)*
+                }
+
+                /// The buffer containing every string in the pool, stored contiguously.
+                pub static BUFFER: [#ty; #len] = [#(#buffer),*];
+
+                /// The strings in the pool, indexed by ID.
+                pub static POOL: #krate::WStrPool<#ty> =
+                    #krate::WStrPool::from_raw_parts(&BUFFER, &[#(#offsets),*]);
+            }
+        })
+    }
+
+    match input.ty {
+        WCharType::U16(_) => quote_pool::<u16>(input, nul_terminated),
+        WCharType::U32(_) => quote_pool::<u32>(input, nul_terminated),
+        WCharType::I16(_) => quote_pool::<i16>(input, nul_terminated),
+        WCharType::I32(_) => quote_pool::<i32>(input, nul_terminated),
+    }
+}
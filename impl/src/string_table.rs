@@ -0,0 +1,58 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use syn::{Error, Result};
+
+use crate::encode::Encode;
+use crate::parse::StringTableInput;
+
+// The number of strings in each block of a string table.
+const BLOCK_LEN: u16 = 16;
+
+// Expands to the `RT_STRING` resources of a string table. String `id` is stored
+// in the block with resource ID `id / 16 + 1`, at index `id % 16`, and each
+// block holds 16 strings as a UTF-16LE length followed by the code units.
+pub fn expand_string_table(input: StringTableInput) -> Result<TokenStream> {
+    let StringTableInput { krate, entries, .. } = input;
+
+    let mut blocks: BTreeMap<u16, [Option<Vec<u16>>; BLOCK_LEN as usize]> = BTreeMap::new();
+
+    for entry in entries {
+        let id: u16 = entry.id.base10_parse()?;
+        let block = blocks.entry(id / BLOCK_LEN + 1).or_default();
+        let string = &mut block[(id % BLOCK_LEN) as usize];
+
+        if string.is_some() {
+            return Err(Error::new(entry.id.span(), "duplicate ID in string table"));
+        }
+
+        let units = u16::encode_str(&entry.literal.value());
+        if units.len() > u16::MAX as usize {
+            return Err(Error::new(
+                entry.literal.span(),
+                "string is too long for a string table",
+            ));
+        }
+
+        *string = Some(units);
+    }
+
+    let blocks = blocks.into_iter().map(|(block_id, strings)| {
+        let mut data = Vec::new();
+
+        for units in strings.iter() {
+            let units = units.as_deref().unwrap_or_default();
+
+            data.extend_from_slice(&(units.len() as u16).to_le_bytes());
+            for unit in units {
+                data.extend_from_slice(&unit.to_le_bytes());
+            }
+        }
+
+        quote::quote! { (#block_id, &[#(#data),*] as &[u8]) }
+    });
+
+    Ok(quote::quote! {
+        #krate::StringTable::from_sorted_blocks(&[#(#blocks),*])
+    })
+}
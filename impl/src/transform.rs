@@ -0,0 +1,108 @@
+use proc_macro2::{Literal, Span};
+use syn::{Error, LitChar, Result};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::names;
+use crate::parse::{Case, Normalization, Transforms};
+
+// Applies the text transforms in a fixed order: named character escapes, then
+// dedent, then case mapping, then normalization, as case mapping can produce
+// denormalized text.
+//
+// Errors in named character escapes are reported at the given span, or within
+// the literal the text is the value of, if any.
+pub fn apply(
+    transforms: &Transforms,
+    text: String,
+    span: Span,
+    literal: Option<&Literal>,
+) -> Result<String> {
+    let mut text = text;
+
+    if transforms.names.is_some() {
+        text = names::resolve(&text, span, literal)?;
+    }
+
+    if transforms.dedent {
+        text = dedent(&text);
+    }
+
+    match transforms.case {
+        Some(Case::Upper) => text = text.to_uppercase(),
+        Some(Case::Lower) => text = text.to_lowercase(),
+        None => {}
+    }
+
+    Ok(match transforms.normalization {
+        Some(Normalization::Nfc) => text.nfc().collect(),
+        Some(Normalization::Nfd) => text.nfd().collect(),
+        Some(Normalization::Nfkc) => text.nfkc().collect(),
+        Some(Normalization::Nfkd) => text.nfkd().collect(),
+        None => text,
+    })
+}
+
+// Removes the indentation common to all non-blank lines, along with a leading
+// newline so the text can start on the line after the opening quote.
+//
+// Indentation is made of spaces and tabs, and blank lines are emptied. As with
+// Python's `textwrap.dedent`, the common indentation is the longest prefix of
+// whitespace shared by the lines, so tabs and spaces are never equal.
+fn dedent(text: &str) -> String {
+    let is_indent = |c: char| c == ' ' || c == '\t';
+    let is_blank = |line: &str| {
+        line.trim_end_matches(&['\r', '\n'][..])
+            .chars()
+            .all(is_indent)
+    };
+
+    let text = text.strip_prefix('\n').unwrap_or(text);
+
+    let mut indent: Option<&str> = None;
+    for line in text.lines().filter(|line| !is_blank(line)) {
+        let line_indent = &line[..line.len() - line.trim_start_matches(is_indent).len()];
+
+        indent = Some(match indent {
+            None => line_indent,
+            Some(indent) => {
+                let common = indent
+                    .bytes()
+                    .zip(line_indent.bytes())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                &indent[..common]
+            }
+        });
+    }
+    let indent = indent.unwrap_or("");
+
+    let mut out = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        if is_blank(line) {
+            out.push_str(line.trim_start_matches(is_indent));
+        } else {
+            out.push_str(&line[indent.len()..]);
+        }
+    }
+    out
+}
+
+// Applies the text transforms to a character literal, which must remain a
+// single character.
+pub fn apply_char(transforms: &Transforms, lit: LitChar) -> Result<LitChar> {
+    let text = apply(
+        transforms,
+        lit.value().to_string(),
+        lit.span(),
+        Some(&lit.token()),
+    )?;
+
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(LitChar::new(c, lit.span())),
+        _ => Err(Error::new(
+            lit.span(),
+            "transformed character literal must be a single character",
+        )),
+    }
+}
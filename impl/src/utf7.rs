@@ -0,0 +1,89 @@
+use proc_macro2::TokenStream;
+use syn::{LitStr, Result};
+
+use crate::encode::Encode;
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+// Encodes the string as RFC 2152 UTF-7, or as IMAP's modified UTF-7 from
+// RFC 3501, where the UTF-16 code units of characters that cannot be written
+// directly are encoded in base64 runs.
+pub fn expand_utf7(literal: LitStr, imap: bool) -> Result<TokenStream> {
+    let text = literal.value();
+    let units = u16::encode_str(&text);
+
+    let (shift, slash) = if imap { (b'&', b',') } else { (b'+', b'/') };
+    let mut bytes = Vec::with_capacity(units.len());
+    let mut run: Option<(u32, u32)> = None;
+
+    for unit in units {
+        if is_direct(unit, imap) {
+            if let Some((bits, len)) = run.take() {
+                if len > 0 {
+                    bytes.push(base64(bits << (6 - len), slash));
+                }
+                // The terminator is optional in UTF-7, unless the next
+                // character would be read as part of the run.
+                if imap || is_base64(unit) || unit == u16::from(b'-') {
+                    bytes.push(b'-');
+                }
+            }
+
+            bytes.push(unit as u8);
+            if unit == u16::from(shift) {
+                bytes.push(b'-');
+            }
+            continue;
+        }
+
+        let (mut bits, mut len) = run.unwrap_or_else(|| {
+            bytes.push(shift);
+            (0, 0)
+        });
+
+        bits = bits << 16 | u32::from(unit);
+        len += 16;
+        while len >= 6 {
+            len -= 6;
+            bytes.push(base64(bits >> len, slash));
+        }
+        bits &= (1 << len) - 1;
+
+        run = Some((bits, len));
+    }
+
+    if let Some((bits, len)) = run {
+        if len > 0 {
+            bytes.push(base64(bits << (6 - len), slash));
+        }
+        bytes.push(b'-');
+    }
+
+    Ok(quote::quote! { &[#(#bytes),*] })
+}
+
+// The characters written as themselves, rather than base64 encoded. For UTF-7
+// these are the RFC 2152 directly encoded characters plus whitespace, with the
+// optional direct characters encoded for safety.
+fn is_direct(unit: u16, imap: bool) -> bool {
+    if imap {
+        return (0x20..=0x7E).contains(&unit);
+    }
+
+    match unit {
+        0x09 | 0x0A | 0x0D | 0x20 | 0x2B => true,
+        0x30..=0x39 | 0x41..=0x5A | 0x61..=0x7A => true,
+        _ => unit < 0x80 && b"'(),-./:?".contains(&(unit as u8)),
+    }
+}
+
+fn is_base64(unit: u16) -> bool {
+    unit < 0x80 && BASE64.contains(&(unit as u8))
+}
+
+fn base64(bits: u32, slash: u8) -> u8 {
+    match BASE64[(bits & 0x3F) as usize] {
+        b'/' => slash,
+        b => b,
+    }
+}
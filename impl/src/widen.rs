@@ -0,0 +1,174 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    Attribute, Error, Expr, ImplItemConst, Item, ItemConst, ItemStatic, Lit, Local, Pat, Result,
+    Type,
+};
+
+use crate::encode;
+use crate::parse::WCharType;
+
+mod kw {
+    syn::custom_keyword!(nul);
+}
+
+pub fn expand_widen(ty: WCharType, mut item: Item) -> Result<TokenStream> {
+    let mut widen = Widen {
+        ty,
+        errors: Vec::new(),
+    };
+    widen.visit_item_mut(&mut item);
+
+    let mut errors = widen.errors.into_iter();
+    match errors.next() {
+        Some(mut err) => {
+            errors.for_each(|e| err.combine(e));
+            Err(err)
+        }
+        None => Ok(item.into_token_stream()),
+    }
+}
+
+struct Widen {
+    ty: WCharType,
+    errors: Vec<Error>,
+}
+
+impl Widen {
+    fn widen(&mut self, attrs: &mut Vec<Attribute>, ty: Option<&mut Type>, expr: &mut Expr) {
+        if let Err(err) = self.try_widen(attrs, ty, expr) {
+            self.errors.push(err);
+        }
+    }
+
+    fn try_widen(
+        &self,
+        attrs: &mut Vec<Attribute>,
+        ty: Option<&mut Type>,
+        expr: &mut Expr,
+    ) -> Result<()> {
+        let nul = match take_wide_attr(attrs)? {
+            Some(nul) => nul,
+            None => return Ok(()),
+        };
+
+        let wchar_ty = self.ty;
+        let (expanded, is_char) = match expr {
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Str(lit) => {
+                    let text = lit.value();
+
+                    if nul {
+                        if text.as_bytes().contains(&0) {
+                            return Err(Error::new(
+                                lit.span(),
+                                "C-style string cannot contain nul characters",
+                            ));
+                        }
+
+                        (encode::expand_str_c(wchar_ty, &text), false)
+                    } else {
+                        (encode::expand_str(wchar_ty, &text), false)
+                    }
+                }
+                Lit::Char(lit) if !nul => (encode::expand_char(wchar_ty, lit.clone())?, true),
+                _ => {
+                    return Err(Error::new_spanned(
+                        lit,
+                        "`#[wide]` expected a string or character literal",
+                    ));
+                }
+            },
+            _ => {
+                return Err(Error::new_spanned(
+                    expr,
+                    "`#[wide]` expected a string or character literal",
+                ));
+            }
+        };
+
+        if let Some(ty) = ty {
+            widen_type(wchar_ty, ty, is_char)?;
+        }
+
+        *expr = syn::parse2(expanded)?;
+
+        Ok(())
+    }
+}
+
+impl VisitMut for Widen {
+    fn visit_item_const_mut(&mut self, item: &mut ItemConst) {
+        self.widen(&mut item.attrs, Some(&mut item.ty), &mut item.expr);
+        visit_mut::visit_item_const_mut(self, item);
+    }
+
+    fn visit_item_static_mut(&mut self, item: &mut ItemStatic) {
+        self.widen(&mut item.attrs, Some(&mut item.ty), &mut item.expr);
+        visit_mut::visit_item_static_mut(self, item);
+    }
+
+    fn visit_impl_item_const_mut(&mut self, item: &mut ImplItemConst) {
+        self.widen(&mut item.attrs, Some(&mut item.ty), &mut item.expr);
+        visit_mut::visit_impl_item_const_mut(self, item);
+    }
+
+    fn visit_local_mut(&mut self, local: &mut Local) {
+        if let Some((_, init)) = &mut local.init {
+            let ty = match &mut local.pat {
+                Pat::Type(pat) => Some(&mut *pat.ty),
+                _ => None,
+            };
+
+            self.widen(&mut local.attrs, ty, init);
+        }
+        visit_mut::visit_local_mut(self, local);
+    }
+}
+
+// Removes the `#[wide]` attribute, returning whether it requested a
+// nul-terminated string.
+fn take_wide_attr(attrs: &mut Vec<Attribute>) -> Result<Option<bool>> {
+    let i = match attrs.iter().position(|attr| attr.path.is_ident("wide")) {
+        Some(i) => i,
+        None => return Ok(None),
+    };
+
+    let attr = attrs.remove(i);
+    if attr.tokens.is_empty() {
+        Ok(Some(false))
+    } else {
+        attr.parse_args::<kw::nul>()?;
+        Ok(Some(true))
+    }
+}
+
+// Replaces `&str` with `&[T]`, or `char` with `T`.
+fn widen_type(wchar_ty: WCharType, ty: &mut Type, is_char: bool) -> Result<()> {
+    match ty {
+        Type::Reference(reference) if !is_char && is_ident(&reference.elem, "str") => {
+            *reference.elem = syn::parse_quote!([#wchar_ty]);
+            Ok(())
+        }
+        _ if is_char && is_ident(ty, "char") => {
+            *ty = syn::parse_quote!(#wchar_ty);
+            Ok(())
+        }
+        _ => Err(Error::new_spanned(
+            ty,
+            if is_char {
+                "`#[wide]` expected a `char` type"
+            } else {
+                "`#[wide]` expected a `&str` type"
+            },
+        )),
+    }
+}
+
+fn is_ident(ty: &Type, ident: &str) -> bool {
+    match ty {
+        Type::Path(path) => path.qself.is_none() && path.path.is_ident(ident),
+        _ => false,
+    }
+}
@@ -0,0 +1,99 @@
+use core::fmt;
+use core::slice;
+
+/// A static string in the memory layout of a COM `BSTR`.
+///
+/// The UTF-16 data is preceded by a 4 byte prefix holding its length in bytes,
+/// excluding the nul-terminator that follows it. A `BSTR` is a pointer to the
+/// data, as returned by [`as_ptr`](Bstr::as_ptr), rather than to the prefix.
+///
+/// Static strings are usually generated with the [`bstr`] macro, which takes
+/// the place of `SysAllocString` for constant strings. As they are not
+/// allocated by `SysAllocString`, they must never be passed to `SysFreeString`
+/// or to APIs that take ownership of the `BSTR`.
+///
+/// The `D` parameter holds the data, including the nul-terminator. It is an
+/// array when the string is created, which can be coerced to the default
+/// unsized `[u16]` to erase the length.
+///
+/// [`bstr`]: crate::bstr
+#[repr(C)]
+pub struct Bstr<D: ?Sized = [u16]> {
+    len: u32,
+    data: D,
+}
+
+impl<const N: usize> Bstr<[u16; N]> {
+    /// Creates a `BSTR` from the UTF-16 code units of a string followed by a
+    /// nul-terminator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the last unit is not nul, or if the length in bytes does not
+    /// fit in the prefix.
+    pub const fn from_units(data: [u16; N]) -> Self {
+        assert!(N > 0 && data[N - 1] == 0, "`BSTR` must be nul-terminated");
+        assert!(N - 1 <= (u32::MAX / 2) as usize, "`BSTR` is too long");
+
+        Bstr {
+            len: (N as u32 - 1) * 2,
+            data,
+        }
+    }
+}
+
+impl Bstr {
+    /// Returns the `BSTR`, a pointer to the first code unit of the data.
+    ///
+    /// The byte length prefix is stored immediately before the pointer, and
+    /// the data is followed by a nul-terminator.
+    pub fn as_ptr(&self) -> *const u16 {
+        // Derive the pointer from the whole string, so it is valid for reading
+        // the prefix as well as the data.
+        let prefix = self as *const Self as *const u32;
+
+        prefix.wrapping_add(1) as *const u16
+    }
+
+    /// Returns the length of the string in bytes, as stored in the prefix.
+    pub const fn byte_len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns the length of the string in UTF-16 code units, excluding the
+    /// nul-terminator.
+    pub const fn len(&self) -> usize {
+        self.data.len() - 1
+    }
+
+    /// Returns `true` if the string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the code units of the string, excluding the nul-terminator.
+    pub fn as_slice(&self) -> &[u16] {
+        &self.data[..self.len()]
+    }
+
+    /// Returns the code units of the string, including the nul-terminator.
+    pub fn as_slice_with_nul(&self) -> &[u16] {
+        &self.data
+    }
+
+    /// Returns the memory of the string, starting at the length prefix and
+    /// ending with the nul-terminator.
+    pub fn as_bytes(&self) -> &[u8] {
+        let len = 4 + self.data.len() * 2;
+
+        // SAFETY: The prefix is immediately followed by the data in the
+        // `#[repr(C)]` layout, without any padding between them.
+        unsafe { slice::from_raw_parts(self as *const Self as *const u8, len) }
+    }
+}
+
+impl fmt::Debug for Bstr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Bstr").field(&self.as_slice()).finish()
+    }
+}
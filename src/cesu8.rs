@@ -0,0 +1,232 @@
+use core::fmt;
+
+/// Encode UTF-16 code units as CESU-8.
+///
+/// Each code unit is encoded as if it were a character, so supplementary
+/// characters become a pair of 3 byte encoded surrogates.
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{cesu8, encode_cesu8};
+///
+/// let bytes: Vec<u8> = encode_cesu8("a🦀".encode_utf16()).collect();
+/// assert_eq!(bytes, cesu8!("a🦀"));
+/// ```
+pub fn encode_cesu8<I: IntoIterator<Item = u16>>(units: I) -> EncodeCesu8<I::IntoIter> {
+    EncodeCesu8::new(units.into_iter(), false)
+}
+
+/// Encode UTF-16 code units as Java's modified UTF-8.
+///
+/// Modified UTF-8 is CESU-8 with nul encoded as `C0 80`, so the encoded string
+/// never contains a nul byte.
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{encode_mutf8, mutf8};
+///
+/// let bytes: Vec<u8> = encode_mutf8("a\0🦀".encode_utf16()).collect();
+/// assert_eq!(bytes, mutf8!("a\0🦀"));
+/// ```
+pub fn encode_mutf8<I: IntoIterator<Item = u16>>(units: I) -> EncodeCesu8<I::IntoIter> {
+    EncodeCesu8::new(units.into_iter(), true)
+}
+
+/// Decode CESU-8 bytes into UTF-16 code units.
+///
+/// Decoding stops after the first error. Surrogates are decoded as is, without
+/// checking that they are paired.
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{cesu8, decode_cesu8, wch};
+///
+/// let units: Result<Vec<u16>, _> = decode_cesu8(cesu8!("a🦀")).collect();
+/// assert_eq!(units.unwrap(), wch!(u16, "a🦀"));
+/// ```
+pub fn decode_cesu8(bytes: &[u8]) -> DecodeCesu8<'_> {
+    DecodeCesu8::new(bytes, false)
+}
+
+/// Decode Java's modified UTF-8 bytes into UTF-16 code units.
+///
+/// Decoding stops after the first error. Surrogates are decoded as is, without
+/// checking that they are paired, as Java strings may contain lone surrogates.
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{decode_mutf8, mutf8, wch};
+///
+/// let units: Result<Vec<u16>, _> = decode_mutf8(mutf8!("a\0🦀")).collect();
+/// assert_eq!(units.unwrap(), wch!(u16, "a\0🦀"));
+///
+/// assert!(decode_mutf8(b"a\0").nth(1).unwrap().is_err());
+/// ```
+pub fn decode_mutf8(bytes: &[u8]) -> DecodeCesu8<'_> {
+    DecodeCesu8::new(bytes, true)
+}
+
+/// An iterator over the CESU-8 or modified UTF-8 encoded bytes of UTF-16 code
+/// units.
+///
+/// Created by [`encode_cesu8`] and [`encode_mutf8`].
+#[derive(Clone, Debug)]
+pub struct EncodeCesu8<I> {
+    units: I,
+    modified: bool,
+    buf: [u8; 3],
+    pos: usize,
+    len: usize,
+}
+
+impl<I> EncodeCesu8<I> {
+    fn new(units: I, modified: bool) -> Self {
+        EncodeCesu8 {
+            units,
+            modified,
+            buf: [0; 3],
+            pos: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = u16>> Iterator for EncodeCesu8<I> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.pos == self.len {
+            let unit = self.units.next()?;
+
+            self.pos = 0;
+            self.len = match unit {
+                0 if self.modified => {
+                    self.buf[..2].copy_from_slice(&[0xC0, 0x80]);
+                    2
+                }
+                0x0000..=0x007F => {
+                    self.buf[0] = unit as u8;
+                    1
+                }
+                0x0080..=0x07FF => {
+                    self.buf[0] = 0xC0 | (unit >> 6) as u8;
+                    self.buf[1] = 0x80 | (unit & 0x3F) as u8;
+                    2
+                }
+                _ => {
+                    self.buf[0] = 0xE0 | (unit >> 12) as u8;
+                    self.buf[1] = 0x80 | ((unit >> 6) & 0x3F) as u8;
+                    self.buf[2] = 0x80 | (unit & 0x3F) as u8;
+                    3
+                }
+            };
+        }
+
+        let b = self.buf[self.pos];
+        self.pos += 1;
+        Some(b)
+    }
+}
+
+/// An iterator over the UTF-16 code units decoded from CESU-8 or modified UTF-8
+/// bytes.
+///
+/// Created by [`decode_cesu8`] and [`decode_mutf8`].
+#[derive(Clone, Debug)]
+pub struct DecodeCesu8<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    modified: bool,
+}
+
+impl<'a> DecodeCesu8<'a> {
+    fn new(bytes: &'a [u8], modified: bool) -> Self {
+        DecodeCesu8 {
+            bytes,
+            pos: 0,
+            modified,
+        }
+    }
+
+    fn continuation(&self, i: usize) -> Option<u16> {
+        match self.bytes.get(self.pos + i) {
+            Some(&b) if b & 0xC0 == 0x80 => Some((b & 0x3F) as u16),
+            _ => None,
+        }
+    }
+
+    fn decode_unit(&self) -> Option<(u16, usize)> {
+        let b0 = self.bytes[self.pos];
+
+        match b0 {
+            0x00 if self.modified => None,
+            0x00..=0x7F => Some((b0 as u16, 1)),
+            // Modified UTF-8 encodes nul as the overlong sequence `C0 80`.
+            0xC0 if self.modified && self.bytes.get(self.pos + 1) == Some(&0x80) => Some((0, 2)),
+            0xC2..=0xDF => {
+                let unit = ((b0 & 0x1F) as u16) << 6 | self.continuation(1)?;
+                Some((unit, 2))
+            }
+            0xE0..=0xEF => {
+                let unit = ((b0 & 0x0F) as u16) << 12
+                    | self.continuation(1)? << 6
+                    | self.continuation(2)?;
+
+                // Reject overlong encodings.
+                if unit < 0x0800 {
+                    return None;
+                }
+                Some((unit, 3))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Iterator for DecodeCesu8<'_> {
+    type Item = Result<u16, Cesu8Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos == self.bytes.len() {
+            return None;
+        }
+
+        match self.decode_unit() {
+            Some((unit, len)) => {
+                self.pos += len;
+                Some(Ok(unit))
+            }
+            None => {
+                let err = Cesu8Error {
+                    valid_up_to: self.pos,
+                };
+                self.pos = self.bytes.len();
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// An error returned when decoding invalid CESU-8 or modified UTF-8.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cesu8Error {
+    valid_up_to: usize,
+}
+
+impl Cesu8Error {
+    /// Returns the index in the input up to which valid code units were
+    /// decoded.
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+impl fmt::Display for Cesu8Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid byte sequence at index {}", self.valid_up_to)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::wide::WideChar;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+}
+
+/// The type of the length prefix of a counted string, one of `u8`, `u16` or
+/// `u32`.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait CountPrefix: private::Sealed {
+    // The width of the prefix in bits.
+    #[doc(hidden)]
+    const BITS: u32;
+}
+
+impl CountPrefix for u8 {
+    const BITS: u32 = 8;
+}
+
+impl CountPrefix for u16 {
+    const BITS: u32 = 16;
+}
+
+impl CountPrefix for u32 {
+    const BITS: u32 = 32;
+}
+
+/// Splits a counted string, as generated by [`wch_counted`], from the start of
+/// a buffer.
+///
+/// The length prefix of type `P` is read from the first unit, or from the first
+/// units in native byte order when `P` is wider than `T`. Returns the string,
+/// without its prefix, and the rest of the buffer, or `None` if the buffer is
+/// too short for the prefix or the count, or if the prefix unit holds a value
+/// that does not fit in `P`.
+///
+/// [`wch_counted`]: crate::wch_counted
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{split_counted, wch, wch_counted};
+///
+/// let foo: &[u16] = wch_counted!(u16, len = u8, "foo");
+/// let bar: &[u16] = wch_counted!(u16, len = u8, "bar");
+/// let buf = [foo, bar].concat();
+///
+/// let (foo, rest) = split_counted::<u8, u16>(&buf).unwrap();
+/// let (bar, rest) = split_counted::<u8, u16>(rest).unwrap();
+///
+/// assert_eq!(foo, wch!(u16, "foo"));
+/// assert_eq!(bar, wch!(u16, "bar"));
+/// assert!(rest.is_empty());
+/// ```
+pub fn split_counted<P: CountPrefix, T: WideChar>(buf: &[T]) -> Option<(&[T], &[T])> {
+    let prefix_len = (P::BITS / T::BITS).max(1) as usize;
+    let prefix = buf.get(..prefix_len)?;
+
+    let mut count = 0u64;
+    for (i, unit) in prefix.iter().enumerate() {
+        let shift = if cfg!(target_endian = "little") {
+            i
+        } else {
+            prefix_len - 1 - i
+        };
+
+        count |= u64::from(unit.to_bits()) << (shift as u32 * T::BITS);
+    }
+
+    if count >> P::BITS != 0 {
+        return None;
+    }
+
+    let rest = &buf[prefix_len..];
+    let count = count as usize;
+    if count > rest.len() {
+        return None;
+    }
+
+    Some(rest.split_at(count))
+}
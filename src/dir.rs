@@ -0,0 +1,72 @@
+use core::fmt;
+
+/// A static table of wide strings embedded from the files of a directory.
+///
+/// Tables are usually generated with the [`include_wch_dir`] macro, which
+/// sorts the files by path at compile time so that lookups are a binary
+/// search.
+///
+/// [`include_wch_dir`]: crate::include_wch_dir
+pub struct WStrDir<T: 'static> {
+    entries: &'static [(&'static str, &'static [T])],
+}
+
+impl<T> WStrDir<T> {
+    /// Creates a table from a slice of entries, sorted by path.
+    ///
+    /// Lookups will not find entries if the slice is not correctly sorted.
+    pub const fn from_sorted_entries(entries: &'static [(&'static str, &'static [T])]) -> Self {
+        WStrDir { entries }
+    }
+
+    /// Returns the contents of the file with the given path, relative to the
+    /// embedded directory and separated by `/`.
+    pub fn get(&self, path: &str) -> Option<&'static [T]> {
+        let entries = self.entries;
+
+        match entries.binary_search_by_key(&path, |&(p, _)| p) {
+            Ok(i) => Some(entries[i].1),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns `true` if the table contains a file with the given path.
+    pub fn contains(&self, path: &str) -> bool {
+        self.get(path).is_some()
+    }
+
+    /// Returns the number of files in the table.
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the table contains no files.
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the entries of the table, sorted by path.
+    pub const fn entries(&self) -> &'static [(&'static str, &'static [T])] {
+        self.entries
+    }
+
+    /// Returns an iterator over the paths and contents of the files, sorted by
+    /// path.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &'static [T])> + 'static {
+        self.entries.iter().copied()
+    }
+}
+
+impl<T> Clone for WStrDir<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for WStrDir<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for WStrDir<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
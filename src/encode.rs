@@ -0,0 +1,225 @@
+// Decodes the character starting at byte `i` of a UTF-8 string, returning the
+// character and its length in bytes.
+//
+// The bytes must be valid UTF-8, as guaranteed by `str`.
+const fn decode_utf8(bytes: &[u8], i: usize) -> (u32, usize) {
+    let b0 = bytes[i] as u32;
+
+    if b0 < 0x80 {
+        (b0, 1)
+    } else if b0 < 0xE0 {
+        let c = ((b0 & 0x1F) << 6) | (bytes[i + 1] as u32 & 0x3F);
+        (c, 2)
+    } else if b0 < 0xF0 {
+        let c = ((b0 & 0x0F) << 12)
+            | ((bytes[i + 1] as u32 & 0x3F) << 6)
+            | (bytes[i + 2] as u32 & 0x3F);
+        (c, 3)
+    } else {
+        let c = ((b0 & 0x07) << 18)
+            | ((bytes[i + 1] as u32 & 0x3F) << 12)
+            | ((bytes[i + 2] as u32 & 0x3F) << 6)
+            | (bytes[i + 3] as u32 & 0x3F);
+        (c, 4)
+    }
+}
+
+/// Returns the number of UTF-16 code units needed to encode a string.
+///
+/// Intended to be used as the array length for [`const_utf16`].
+pub const fn utf16_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+
+    let mut i = 0;
+    let mut len = 0;
+    while i < bytes.len() {
+        let (c, n) = decode_utf8(bytes, i);
+        i += n;
+        len += if c >= 0x10000 { 2 } else { 1 };
+    }
+    len
+}
+
+/// Returns the number of UTF-32 code units needed to encode a string.
+///
+/// Intended to be used as the array length for [`const_utf32`].
+pub const fn utf32_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+
+    let mut i = 0;
+    let mut len = 0;
+    while i < bytes.len() {
+        // Count every byte that is not a continuation byte.
+        if bytes[i] & 0xC0 != 0x80 {
+            len += 1;
+        }
+        i += 1;
+    }
+    len
+}
+
+const fn contains_nul(s: &str) -> bool {
+    let bytes = s.as_bytes();
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == 0 {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+const fn encode_utf16<const N: usize>(s: &str, mut buf: [u16; N]) -> [u16; N] {
+    let bytes = s.as_bytes();
+
+    let mut i = 0;
+    let mut j = 0;
+    while i < bytes.len() {
+        let (c, n) = decode_utf8(bytes, i);
+        i += n;
+
+        if c >= 0x10000 {
+            let c = c - 0x10000;
+            buf[j] = 0xD800 | (c >> 10) as u16;
+            buf[j + 1] = 0xDC00 | (c & 0x3FF) as u16;
+            j += 2;
+        } else {
+            buf[j] = c as u16;
+            j += 1;
+        }
+    }
+    buf
+}
+
+const fn encode_utf32<const N: usize>(s: &str, mut buf: [u32; N]) -> [u32; N] {
+    let bytes = s.as_bytes();
+
+    let mut i = 0;
+    let mut j = 0;
+    while i < bytes.len() {
+        let (c, n) = decode_utf8(bytes, i);
+        i += n;
+
+        buf[j] = c;
+        j += 1;
+    }
+    buf
+}
+
+/// Encode a string as a UTF-16 wide string in a const context.
+///
+/// Unlike [`wch`], this works with any `&str` constant, including strings
+/// built with `concat!` and `env!`. The array length `N` must equal
+/// [`utf16_len`] of the string, otherwise evaluation panics.
+///
+/// [`wch`]: crate::wch
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{const_utf16, utf16_len, wch};
+///
+/// const NAME: &str = concat!("foo", "bar");
+/// const WIDE: [u16; utf16_len(NAME)] = const_utf16(NAME);
+///
+/// assert_eq!(&WIDE, wch!(u16, "foobar"));
+/// ```
+pub const fn const_utf16<const N: usize>(s: &str) -> [u16; N] {
+    if utf16_len(s) != N {
+        panic!("array length does not match the UTF-16 length of the string");
+    }
+
+    encode_utf16(s, [0; N])
+}
+
+/// Encode a string as a C-style nul-terminated UTF-16 wide string in a const
+/// context.
+///
+/// Unlike [`wchz`], this works with any `&str` constant, including strings
+/// built with `concat!` and `env!`. The array length `N` must equal
+/// [`utf16_len`] of the string plus one for the nul-terminator, otherwise
+/// evaluation panics. Evaluation also panics if the string contains nul
+/// characters.
+///
+/// [`wchz`]: crate::wchz
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{const_utf16z, utf16_len, wchz};
+///
+/// const NAME: &str = concat!("foo", "bar");
+/// const WIDE: [u16; utf16_len(NAME) + 1] = const_utf16z(NAME);
+///
+/// assert_eq!(&WIDE, wchz!(u16, "foobar"));
+/// ```
+pub const fn const_utf16z<const N: usize>(s: &str) -> [u16; N] {
+    if contains_nul(s) {
+        panic!("C-style string cannot contain nul characters");
+    }
+    if utf16_len(s) + 1 != N {
+        panic!("array length does not match the UTF-16 length of the string plus a nul-terminator");
+    }
+
+    encode_utf16(s, [0; N])
+}
+
+/// Encode a string as a UTF-32 wide string in a const context.
+///
+/// Unlike [`wch`], this works with any `&str` constant, including strings
+/// built with `concat!` and `env!`. The array length `N` must equal
+/// [`utf32_len`] of the string, otherwise evaluation panics.
+///
+/// [`wch`]: crate::wch
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{const_utf32, utf32_len, wch};
+///
+/// const NAME: &str = concat!("foo", "bar");
+/// const WIDE: [u32; utf32_len(NAME)] = const_utf32(NAME);
+///
+/// assert_eq!(&WIDE, wch!(u32, "foobar"));
+/// ```
+pub const fn const_utf32<const N: usize>(s: &str) -> [u32; N] {
+    if utf32_len(s) != N {
+        panic!("array length does not match the UTF-32 length of the string");
+    }
+
+    encode_utf32(s, [0; N])
+}
+
+/// Encode a string as a C-style nul-terminated UTF-32 wide string in a const
+/// context.
+///
+/// Unlike [`wchz`], this works with any `&str` constant, including strings
+/// built with `concat!` and `env!`. The array length `N` must equal
+/// [`utf32_len`] of the string plus one for the nul-terminator, otherwise
+/// evaluation panics. Evaluation also panics if the string contains nul
+/// characters.
+///
+/// [`wchz`]: crate::wchz
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{const_utf32z, utf32_len, wchz};
+///
+/// const NAME: &str = concat!("foo", "bar");
+/// const WIDE: [u32; utf32_len(NAME) + 1] = const_utf32z(NAME);
+///
+/// assert_eq!(&WIDE, wchz!(u32, "foobar"));
+/// ```
+pub const fn const_utf32z<const N: usize>(s: &str) -> [u32; N] {
+    if contains_nul(s) {
+        panic!("C-style string cannot contain nul characters");
+    }
+    if utf32_len(s) + 1 != N {
+        panic!("array length does not match the UTF-32 length of the string plus a nul-terminator");
+    }
+
+    encode_utf32(s, [0; N])
+}
@@ -17,11 +17,150 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[doc(hidden)]
 pub use wchar_impl as _impl;
 
+/// Derive wide string names for the variants of an enum.
+///
+/// The derive generates two inherent methods on the enum:
+///
+/// - `fn wide_name(&self) -> &'static [T]`, returning the name of the variant.
+/// - `fn from_wide(name: &[T]) -> Option<Self>`, the inverse of `wide_name`.
+///
+/// The wide character type is given by the `#[wide(T)]` attribute on the enum,
+/// where `T` is one of `u16`, `u32`, `i16` or `i32`. With `#[wide(T, nul)]` the
+/// names are C-style nul-terminated strings, and `from_wide` expects the
+/// nul-terminator to be included.
+///
+/// Names default to the name of the variant, and can be changed with the
+/// `#[wide(rename = "...")]` attribute on the variant.
+///
+/// The derive only supports enums with unit variants.
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{wch, wchz, WideName};
+///
+/// #[derive(Debug, PartialEq, WideName)]
+/// #[wide(u16, nul)]
+/// enum WindowClass {
+///     Button,
+///     #[wide(rename = "Edit")]
+///     TextBox,
+/// }
+///
+/// assert_eq!(WindowClass::Button.wide_name(), wchz!(u16, "Button"));
+/// assert_eq!(WindowClass::TextBox.wide_name(), wchz!(u16, "Edit"));
+///
+/// assert_eq!(WindowClass::from_wide(wchz!(u16, "Edit")), Some(WindowClass::TextBox));
+/// assert_eq!(WindowClass::from_wide(wch!(u16, "Edit")), None);
+/// ```
+pub use wchar_impl::WideName;
+
+/// Widen the marked string literals within an item to UTF-16 or UTF-32 wide
+/// strings.
+///
+/// The attribute can be applied to any item, such as a `mod` or `fn`, and takes
+/// the output character type as its argument. Within the item, the values of
+/// `const` items, `static` items and `let` statements marked with `#[wide]` are
+/// rewritten as if by [`wch`], with a type of `&str` becoming `&[T]`. Marking
+/// them with `#[wide(nul)]` instead rewrites them as if by [`wchz`].
+///
+/// Character literals with a type of `char` are also supported by `#[wide]`,
+/// becoming a single wide character of type `T`.
+///
+/// Literals that are not marked, such as arguments to `format!`, are left
+/// alone.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::{wch, wchz};
+/// #[wchar::widen(u16)]
+/// mod ffi {
+///     #[wide]
+///     pub const CLASS_NAME: &str = "Button";
+///     #[wide(nul)]
+///     pub const WINDOW_TITLE: &'static str = "Hello, world!";
+///     #[wide]
+///     pub const SEPARATOR: char = '\\';
+///
+///     pub fn message(name: &str) -> String {
+///         format!("Hello, {}!", name)
+///     }
+/// }
+///
+/// assert_eq!(ffi::CLASS_NAME, wch!(u16, "Button"));
+/// assert_eq!(ffi::WINDOW_TITLE, wchz!(u16, "Hello, world!"));
+/// assert_eq!(ffi::SEPARATOR, wch!(u16, '\\'));
+/// assert_eq!(ffi::message("Rust"), "Hello, Rust!");
+/// ```
+pub use wchar_impl::widen;
+
+#[doc(inline)]
+pub use crate::bstr::Bstr;
+#[doc(inline)]
+pub use crate::cesu8::{
+    decode_cesu8, decode_mutf8, encode_cesu8, encode_mutf8, Cesu8Error, DecodeCesu8, EncodeCesu8,
+};
+#[doc(inline)]
+pub use crate::counted::{split_counted, CountPrefix};
+#[doc(inline)]
+pub use crate::dir::WStrDir;
+#[doc(inline)]
+pub use crate::encode::{
+    const_utf16, const_utf16z, const_utf32, const_utf32z, utf16_len, utf32_len,
+};
+#[doc(inline)]
+pub use crate::map::WStrMap;
+#[doc(inline)]
+pub use crate::pool::WStrPool;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use crate::string::WString;
+#[doc(inline)]
+pub use crate::string_table::StringTable;
+#[doc(inline)]
+pub use crate::unicode_string::UnicodeString;
+#[doc(inline)]
+pub use crate::utf7::{
+    decode_imap_utf7, decode_utf7, encode_imap_utf7, encode_utf7, DecodeUtf7, EncodeUtf7, Utf7Error,
+};
+#[doc(inline)]
+pub use crate::wide::WideChar;
+#[doc(inline)]
+pub use crate::write::WSliceWriter;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use crate::write::WVecWriter;
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub use crate::write::__wformat;
+
+mod bstr;
+mod cesu8;
+mod counted;
+mod dir;
+mod encode;
+mod map;
+mod pool;
+#[cfg(feature = "alloc")]
+mod string;
+mod string_table;
+mod unicode_string;
+mod utf7;
+mod wide;
+mod write;
+
+// The `$d` argument is a literal `$` token, used to declare the repetitions
+// of the nested macro.
 macro_rules! wchar_t {
-    ($ty:ident) => {
+    ($d:tt $ty:ident) => {
         /// Platform wide character type.
         #[allow(non_camel_case_types)]
         pub type wchar_t = $ty;
@@ -29,30 +168,30 @@ macro_rules! wchar_t {
         #[doc(hidden)]
         #[macro_export]
         macro_rules! __expand_platform_wchar {
-            ($macro:ident, $string:literal) => {
-                $crate::_impl::$macro!($ty, $string)
+            ($d macro:ident, $d ($d input:tt)*) => {
+                $crate::_impl::$d macro! { $ty, $d ($d input)* }
             };
         }
     };
-    () => {
+    ($d:tt) => {
         #[doc(hidden)]
         #[macro_export]
         macro_rules! __expand_platform_wchar {
-            ($macro:ident, $string:literal) => {
-                $crate::_core::compile_error!("native wchar_t not support for this platform");
+            ($d ($d input:tt)*) => {
+                ::core::compile_error!("native wchar_t not support for this platform");
             };
         }
     };
 }
 
 #[cfg(wchar_t = "u16")]
-wchar_t!(u16);
+wchar_t!($ u16);
 #[cfg(wchar_t = "u32")]
-wchar_t!(u32);
+wchar_t!($ u32);
 #[cfg(wchar_t = "i32")]
-wchar_t!(i32);
+wchar_t!($ i32);
 #[cfg(not(any(wchar_t = "u16", wchar_t = "u32", wchar_t = "i32")))]
-wchar_t!();
+wchar_t!($);
 
 /// Generate a UTF-16 or UTF-32 wide string from a string literal.
 ///
@@ -61,6 +200,46 @@ wchar_t!();
 /// The first argument is the output character type, if no type is specified the
 /// platform native `wchar_t` will be used.
 ///
+/// # Text transforms
+///
+/// The literal may be preceded by text transforms, which are applied at compile
+/// time before the string is encoded. Transforms follow the character type,
+/// which can be given as `wchar_t` for the platform native type:
+///
+/// - `names` resolves `\N{NAME}` escapes to the character with the given
+///   Unicode name, such as `\N{RIGHT-TO-LEFT MARK}`. The escapes are resolved
+///   in the value of the literal, after its Rust escapes. As Rust rejects an
+///   unknown `\N` escape, they are written with an escaped backslash, as in
+///   `"\\N{EM DASH}\n"`, or in raw string literals, where `\\N{` is kept as a
+///   literal `\N{`. Requires the `unicode-names` feature.
+/// - `dedent` removes the indentation common to all non-blank lines, along with
+///   a leading newline.
+/// - `upper` and `lower` map the string to upper or lower case.
+/// - `nfc`, `nfd`, `nfkc` and `nfkd` normalize the string to the given Unicode
+///   normalization form.
+///
+/// Transforms are applied in the order listed above, regardless of the order
+/// they are given in. A character literal must still be a single character
+/// after being transformed.
+///
+/// # Byte literals
+///
+/// Byte string and byte literals are accepted for legacy text, and must be
+/// preceded by their source encoding, either `latin1` for ISO-8859-1 or
+/// `cp1252` for Windows-1252. The bytes are decoded at compile time, before any
+/// text transforms are applied.
+///
+/// # Ill-formed strings
+///
+/// The `ill_formed` option enables `\u[XXXX]` escapes, each inserting the code
+/// unit given by 1 to 8 hex digits as is. This allows generating ill-formed
+/// UTF-16 or UTF-32, such as strings containing lone surrogates, that cannot be
+/// represented by a Rust string. As with `names`, the escapes are written in
+/// raw string literals.
+///
+/// The `ill_formed` option cannot be combined with a source encoding or text
+/// transforms.
+///
 /// # Notes
 ///
 /// Whilst this macro can be used for C-style nul-terminated wide strings, no
@@ -95,10 +274,43 @@ wchar_t!();
 ///
 /// assert_eq!(wide_str, expected);
 /// ```
+///
+/// Text transforms:
+///
+/// ```
+/// # use wchar::wch;
+/// assert_eq!(wch!(u16, upper, "foo"), wch!(u16, "FOO"));
+/// assert_eq!(wch!(u16, nfc, "e\u{301}"), wch!(u16, "\u{e9}"));
+/// assert_eq!(
+///     wch!(u16, dedent, "
+///         usage: foo [options]
+///           -h  print help
+///     "),
+///     wch!(u16, "usage: foo [options]\n  -h  print help\n"),
+/// );
+/// ```
+///
+/// Byte strings:
+///
+/// ```
+/// # use wchar::wch;
+/// assert_eq!(wch!(u16, latin1, b"caf\xE9"), wch!(u16, "café"));
+/// assert_eq!(wch!(u16, cp1252, b"\x80 5"), wch!(u16, "€ 5"));
+/// ```
+///
+/// Ill-formed UTF-16, with a lone surrogate:
+///
+/// ```
+/// # use wchar::wch;
+/// assert_eq!(wch!(u16, ill_formed, r"foo\u[D800]"), &[0x0066, 0x006F, 0x006F, 0xD800]);
+/// ```
 #[macro_export]
 macro_rules! wch {
-    ($ty:ident, $string:literal) => {
-        $crate::_impl::wch!($ty, $string)
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wch, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::wch!($ty, $($input)*)
     };
     ($string:literal) => {
         $crate::__expand_platform_wchar!(wch, $string)
@@ -116,6 +328,12 @@ macro_rules! wch {
 /// The first argument is the output character type, if no type is specified the
 /// platform native `wchar_t` will be used.
 ///
+/// The literal may be preceded by text transforms, see [`wch`] for details.
+/// Validations are made after the transforms are applied.
+///
+/// Ill-formed strings are only generated when explicitly enabled by the
+/// `ill_formed` option, see [`wch`] for details.
+///
 /// # Examples
 ///
 /// Basic usage (platform native):
@@ -146,51 +364,1078 @@ macro_rules! wch {
 /// ```
 #[macro_export]
 macro_rules! wchz {
-    ($ty:ident, $string:literal) => {
-        $crate::_impl::wchz!($ty, $string)
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wchz, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::wchz!($ty, $($input)*)
     };
     ($string:literal) => {
         $crate::__expand_platform_wchar!(wchz, $string)
     };
 }
 
-/// Generate a UTF-16 or UTF-32 wide string from a UTF-8 encoded file.
+/// Generate a UTF-16 or UTF-32 wide string from a string literal using C's
+/// escape rules, for literals ported from C sources.
 ///
-/// The generated output takes the form of a slice of wide characters.
+/// The generated output takes the form of a slice of wide characters, holding
+/// exactly the code units a C compiler emits for the equivalent `L"..."`
+/// literal with a `wchar_t` of the output character type.
+///
+/// The literal is usually a raw string literal, as C escapes such as `\e` are
+/// not valid in Rust string literals. The following escapes are supported:
+///
+/// - `\'`, `\"`, `\?`, `\\`, `\a`, `\b`, `\e`, `\f`, `\n`, `\r`, `\t` and
+///   `\v`.
+/// - `\ooo`, an octal escape of one to three digits.
+/// - `\xh...`, a hex escape of any number of digits, which must fit within a
+///   single code unit.
+/// - `\uhhhh` and `\Uhhhhhhhh`, universal character names, which are encoded as
+///   UTF-16 or UTF-32. As in C, they cannot name characters below U+00A0 other
+///   than `$`, `@` and `` ` ``.
+///
+/// Octal and hex escapes insert a single code unit as is, which may be a lone
+/// surrogate, so the output is not necessarily valid UTF-16 or UTF-32.
 ///
 /// The first argument is the output character type, if no type is specified the
 /// platform native `wchar_t` will be used.
 ///
-/// # Notes
+/// # Examples
 ///
-/// Whilst this macro can be used for C-style nul-terminated wide strings, no
-/// validations are made about internal nul characters. If your strings need to
-/// be nul-terminated it is recommended to use [`include_wchz`].
+/// ```
+/// # use wchar::{wch, wch_c};
+/// assert_eq!(wch_c!(u16, r"\x41\102\e[0m\U0001F600"), wch!(u16, "AB\x1B[0m😀"));
+/// assert_eq!(wch_c!(u16, r"\xD800"), &[0xD800]);
+/// assert_eq!(wch_c!(u32, r"\x1F600"), &[0x1F600]);
+/// ```
 #[macro_export]
-macro_rules! include_wch {
-    ($ty:ident, $string:literal) => {
-        $crate::_impl::include_wch!($ty, $string)
+macro_rules! wch_c {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wch_c, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::wch_c!($ty, $($input)*)
     };
     ($string:literal) => {
-        $crate::__expand_platform_wchar!(include_wch, $string)
+        $crate::__expand_platform_wchar!(wch_c, $string)
     };
 }
 
-/// Generate a UTF-16 or UTF-32 wide string from a UTF-8 encoded file.
+/// Generate a C-style nul-terminated UTF-16 or UTF-32 wide string from a
+/// string literal using C's escape rules.
 ///
-/// Validations are made that the given string does not contain nul characters.
+/// Validations are made that the given string does not contain nul characters,
+/// including those inserted by escapes.
 ///
 /// The generated output takes the form of a slice of wide characters, with a
 /// nul-terminator as the last wide character.
 ///
 /// The first argument is the output character type, if no type is specified the
 /// platform native `wchar_t` will be used.
+///
+/// See [`wch_c`] for the supported escapes.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::{wchz, wchz_c};
+/// assert_eq!(wchz_c!(u32, r"tab\there"), wchz!(u32, "tab\there"));
+/// ```
 #[macro_export]
-macro_rules! include_wchz {
-    ($ty:ident, $string:literal) => {
-        $crate::_impl::include_wchz!($ty, $string)
+macro_rules! wchz_c {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wchz_c, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::wchz_c!($ty, $($input)*)
     };
     ($string:literal) => {
-        $crate::__expand_platform_wchar!(include_wchz, $string)
+        $crate::__expand_platform_wchar!(wchz_c, $string)
+    };
+}
+
+/// Generate a counted UTF-16 or UTF-32 wide string from a string literal, with
+/// the length of the string stored before it, as used by Pascal-style strings.
+///
+/// The `len` argument gives the type of the length prefix, one of `u8`, `u16`
+/// or `u32`, which counts the wide characters of the string. The prefix takes
+/// up the first wide character, or the first two in native byte order when it
+/// is wider than the character type. A string too long for the prefix fails to
+/// compile.
+///
+/// The generated output takes the form of a slice of wide characters, starting
+/// with the length prefix. See [`split_counted`] to parse it at runtime.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::wch_counted;
+/// assert_eq!(wch_counted!(u16, len = u16, "foo"), &[3, 0x0066, 0x006F, 0x006F]);
+/// assert_eq!(wch_counted!(u32, len = u8, "🦀"), &[1, 0x1F980]);
+///
+/// #[cfg(target_endian = "little")]
+/// assert_eq!(wch_counted!(u16, len = u32, "a"), &[1, 0, 0x0061]);
+/// ```
+#[macro_export]
+macro_rules! wch_counted {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wch_counted, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::wch_counted!($ty, $($input)*)
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wch_counted, $($input)*)
+    };
+}
+
+/// Generate a string in a legacy single-byte code page from a string literal,
+/// for use with `...A` APIs.
+///
+/// The generated output takes the form of a slice of bytes.
+///
+/// The first argument is the code page number, which is one of:
+///
+/// - `1252`, Windows-1252 (Western European).
+/// - `437`, the original IBM PC code page used by DOS.
+/// - `850`, the DOS Western European code page.
+///
+/// Characters that cannot be represented in the code page are rejected at
+/// compile time.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::cp;
+/// assert_eq!(cp!(1252, "café €5"), b"caf\xE9 \x805");
+/// assert_eq!(cp!(437, "┌─┐"), b"\xDA\xC4\xBF");
+/// assert_eq!(cp!(850, "Ø"), b"\x9D");
+/// ```
+#[macro_export]
+macro_rules! cp {
+    ($page:literal, $string:literal) => {
+        $crate::_impl::cp!($page, $string)
+    };
+}
+
+/// Generate a C-style nul-terminated string in a legacy single-byte code page
+/// from a string literal.
+///
+/// Validations are made that the given string does not contain nul characters.
+///
+/// The generated output takes the form of a slice of bytes, with a
+/// nul-terminator as the last byte.
+///
+/// See [`cp`] for the supported code pages.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::cpz;
+/// assert_eq!(cpz!(1252, "naïve"), b"na\xEFve\0");
+/// ```
+#[macro_export]
+macro_rules! cpz {
+    ($page:literal, $string:literal) => {
+        $crate::_impl::cpz!($page, $string)
+    };
+}
+
+/// Generate a static UTF-16 string in the memory layout of a COM `BSTR`.
+///
+/// The string is preceded by its length in bytes and followed by a
+/// nul-terminator, so [`Bstr::as_ptr`] can be passed to APIs taking a `BSTR`,
+/// such as the names given to `IDispatch::GetIDsOfNames`, without allocating
+/// it with `SysAllocString`. Nul characters are allowed, as the length is
+/// stored in the prefix.
+///
+/// The generated output takes the form of a `&'static` [`Bstr`].
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::{bstr, Bstr};
+/// const NAME: &Bstr = bstr!("Name");
+///
+/// assert_eq!(NAME.byte_len(), 8);
+/// assert_eq!(NAME.as_slice_with_nul(), [0x004E, 0x0061, 0x006D, 0x0065, 0x0000]);
+/// assert_eq!(unsafe { *NAME.as_ptr() }, 0x004E);
+/// ```
+#[macro_export]
+macro_rules! bstr {
+    ($string:literal) => {
+        $crate::_impl::bstr!($crate, $string)
+    };
+}
+
+/// Generate a constant NT `UNICODE_STRING` from a string literal.
+///
+/// The buffer is the UTF-16 string generated by [`wch`], which also accepts the
+/// same text transforms, and the byte lengths are computed at compile time.
+/// Strings longer than `u16::MAX` bytes fail to compile.
+///
+/// The generated output takes the form of a [`UnicodeString`].
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::{unicode_string, wch, UnicodeString};
+/// const DEVICE: UnicodeString = unicode_string!("\\Device\\Example");
+///
+/// assert_eq!(DEVICE.length(), 30);
+/// assert_eq!(DEVICE.maximum_length(), 30);
+/// assert_eq!(DEVICE.as_slice(), wch!(u16, "\\Device\\Example"));
+///
+/// assert_eq!(unicode_string!(upper, "abc").as_slice(), wch!(u16, "ABC"));
+/// ```
+#[macro_export]
+macro_rules! unicode_string {
+    ($($input:tt)*) => {{
+        // Evaluated as a constant, so that the length check fails to compile.
+        const UNICODE_STRING: $crate::UnicodeString =
+            $crate::UnicodeString::from_units($crate::wch!(u16, $($input)*));
+        UNICODE_STRING
+    }};
+}
+
+/// Generate a CESU-8 encoded string from a string literal.
+///
+/// CESU-8 encodes each UTF-16 code unit as if it were a character, so
+/// supplementary characters become a pair of 3 byte encoded surrogates.
+///
+/// The generated output takes the form of a slice of bytes.
+///
+/// See [`encode_cesu8`] and [`decode_cesu8`] for runtime conversions.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::cesu8;
+/// assert_eq!(cesu8!("a🦀"), b"a\xED\xA0\xBE\xED\xB6\x80");
+/// ```
+#[macro_export]
+macro_rules! cesu8 {
+    ($string:literal) => {
+        $crate::_impl::cesu8!($string)
+    };
+}
+
+/// Generate a C-style nul-terminated CESU-8 encoded string from a string
+/// literal.
+///
+/// Validations are made that the given string does not contain nul characters.
+///
+/// The generated output takes the form of a slice of bytes, with a
+/// nul-terminator as the last byte.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::cesu8z;
+/// assert_eq!(cesu8z!("é"), b"\xC3\xA9\0");
+/// ```
+#[macro_export]
+macro_rules! cesu8z {
+    ($string:literal) => {
+        $crate::_impl::cesu8z!($string)
+    };
+}
+
+/// Generate a string encoded as Java's modified UTF-8 from a string literal,
+/// for use with JNI.
+///
+/// Modified UTF-8 is CESU-8 with nul encoded as `C0 80`, so the encoded string
+/// never contains a nul byte.
+///
+/// The generated output takes the form of a slice of bytes.
+///
+/// See [`encode_mutf8`] and [`decode_mutf8`] for runtime conversions.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::mutf8;
+/// assert_eq!(mutf8!("a\0🦀"), b"a\xC0\x80\xED\xA0\xBE\xED\xB6\x80");
+/// ```
+#[macro_export]
+macro_rules! mutf8 {
+    ($string:literal) => {
+        $crate::_impl::mutf8!($string)
+    };
+}
+
+/// Generate a C-style nul-terminated string encoded as Java's modified UTF-8
+/// from a string literal, such as the names and signatures passed to JNI's
+/// `GetMethodID`.
+///
+/// As modified UTF-8 encodes nul as `C0 80`, the given string may contain nul
+/// characters.
+///
+/// The generated output takes the form of a slice of bytes, with a
+/// nul-terminator as the last byte.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::mutf8z;
+/// assert_eq!(mutf8z!("(I)V"), b"(I)V\0");
+/// assert_eq!(mutf8z!("\0"), b"\xC0\x80\0");
+/// ```
+#[macro_export]
+macro_rules! mutf8z {
+    ($string:literal) => {
+        $crate::_impl::mutf8z!($string)
+    };
+}
+
+/// Generate a UTF-7 encoded string from a string literal, as specified by
+/// RFC 2152.
+///
+/// Only the directly encoded characters of RFC 2152 and whitespace are written
+/// as themselves, the optional direct characters are base64 encoded.
+///
+/// The generated output takes the form of a slice of bytes.
+///
+/// See [`encode_utf7`] and [`decode_utf7`] for runtime conversions.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::utf7;
+/// assert_eq!(utf7!("A≢Α."), b"A+ImIDkQ.");
+/// assert_eq!(utf7!("£1"), b"+AKM-1");
+/// ```
+#[macro_export]
+macro_rules! utf7 {
+    ($string:literal) => {
+        $crate::_impl::utf7!($string)
+    };
+}
+
+/// Generate an IMAP modified UTF-7 encoded mailbox name from a string literal,
+/// as specified by RFC 3501.
+///
+/// The generated output takes the form of a slice of bytes.
+///
+/// See [`encode_imap_utf7`] and [`decode_imap_utf7`] for runtime conversions.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::imap_utf7;
+/// assert_eq!(imap_utf7!("~peter/mail/台北/日本語"), b"~peter/mail/&U,BTFw-/&ZeVnLIqe-");
+/// assert_eq!(imap_utf7!("Q&A"), b"Q&-A");
+/// ```
+#[macro_export]
+macro_rules! imap_utf7 {
+    ($string:literal) => {
+        $crate::_impl::imap_utf7!($string)
+    };
+}
+
+/// Generate a slice pattern matching a UTF-16 or UTF-32 wide string literal.
+///
+/// The generated output takes the form of a slice pattern of integer literals,
+/// such as `[0x0066, 0x006F, 0x006F]`, for use in `match` arms and `let`
+/// bindings. If the literal is followed by `..` a rest pattern is appended,
+/// matching any slice that starts with the string.
+///
+/// A character literal generates a single integer literal pattern.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::{wch, wch_pat};
+/// fn command(units: &[u16]) -> &str {
+///     match units {
+///         wch_pat!(u16, "open") => "open",
+///         wch_pat!(u16, "close") => "close",
+///         wch_pat!(u16, "set ", ..) => "set",
+///         [wch_pat!(u16, '#'), ..] => "comment",
+///         _ => "unknown",
+///     }
+/// }
+///
+/// assert_eq!(command(wch!(u16, "open")), "open");
+/// assert_eq!(command(wch!(u16, "close")), "close");
+/// assert_eq!(command(wch!(u16, "set foo")), "set");
+/// assert_eq!(command(wch!(u16, "# open")), "comment");
+/// assert_eq!(command(wch!(u16, "opening")), "unknown");
+/// ```
+#[macro_export]
+macro_rules! wch_pat {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wch_pat, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::wch_pat!($ty, $($input)*)
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wch_pat, $($input)*)
+    };
+}
+
+/// Generate a slice pattern matching a C-style nul-terminated UTF-16 or UTF-32
+/// wide string literal.
+///
+/// Validations are made that the given string does not contain nul characters.
+///
+/// The generated output takes the form of a slice pattern of integer literals,
+/// with a nul-terminator as the last element. If the literal is followed by
+/// `..` a rest pattern is appended after the nul-terminator.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::{wchz, wchz_pat};
+/// fn is_open(units: &[u32]) -> bool {
+///     matches!(units, wchz_pat!(u32, "open"))
+/// }
+///
+/// assert!(is_open(wchz!(u32, "open")));
+/// assert!(!is_open(&[0x006F, 0x0070, 0x0065, 0x006E]));
+/// ```
+#[macro_export]
+macro_rules! wchz_pat {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wchz_pat, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::wchz_pat!($ty, $($input)*)
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wchz_pat, $($input)*)
+    };
+}
+
+/// Generate a UTF-16 or UTF-32 wide string by formatting arguments at compile
+/// time.
+///
+/// The generated output takes the form of a slice of wide characters.
+///
+/// The format string uses the same syntax as [`format!`], supporting
+/// positional and named arguments, fill, alignment, sign, `#`, `0`, width and
+/// precision, with the `?`, `x`, `X`, `o` and `b` format types. Width and
+/// precision cannot be given as arguments.
+///
+/// Arguments must be known at compile time, and are limited to string,
+/// character, integer and boolean literals, and `env!` invocations. Runtime
+/// values, including implicitly captured variables, are rejected.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// [`format!`]: https://doc.rust-lang.org/std/macro.format.html
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::{wch, wformat_const};
+/// const VERSION: &[u16] = wformat_const!(
+///     u16,
+///     "{} v{} ({arch}, build {:04})",
+///     env!("CARGO_PKG_NAME"),
+///     "1.2",
+///     42,
+///     arch = "x86_64",
+/// );
+///
+/// assert_eq!(VERSION, wch!(u16, "wchar v1.2 (x86_64, build 0042)"));
+/// ```
+#[macro_export]
+macro_rules! wformat_const {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wformat_const, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::wformat_const!($ty, $($input)*)
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wformat_const, $($input)*)
+    };
+}
+
+/// Generate a C-style nul-terminated UTF-16 or UTF-32 wide string by
+/// formatting arguments at compile time.
+///
+/// Validations are made that the formatted string does not contain nul
+/// characters.
+///
+/// The generated output takes the form of a slice of wide characters, with a
+/// nul-terminator as the last wide character.
+///
+/// See [`wformat_const`] for the supported format syntax and arguments.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::{wchz, wformatz_const};
+/// const TITLE: &[u16] = wformatz_const!(u16, "{:-^9}", "title");
+///
+/// assert_eq!(TITLE, wchz!(u16, "--title--"));
+/// ```
+#[macro_export]
+macro_rules! wformatz_const {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wformatz_const, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::wformatz_const!($ty, $($input)*)
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wformatz_const, $($input)*)
+    };
+}
+
+/// Generate a UTF-16 or UTF-32 wide string from an environment variable at
+/// compile time.
+///
+/// The generated output takes the form of a slice of wide characters.
+///
+/// As with [`env!`], compilation fails if the variable is not defined, and
+/// the crate is rebuilt when its value changes.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// [`env!`]: https://doc.rust-lang.org/std/macro.env.html
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::{env_wch, wch};
+/// const NAME: &[u16] = env_wch!(u16, "CARGO_PKG_NAME");
+///
+/// assert_eq!(NAME, wch!(u16, "wchar"));
+/// ```
+#[macro_export]
+macro_rules! env_wch {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(env_wch, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::env_wch!($ty, $($input)*)
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(env_wch, $($input)*)
+    };
+}
+
+/// Generate a C-style nul-terminated UTF-16 or UTF-32 wide string from an
+/// environment variable at compile time.
+///
+/// The generated output takes the form of a slice of wide characters, with a
+/// nul-terminator as the last wide character.
+///
+/// See [`env_wch`] for details.
+#[macro_export]
+macro_rules! env_wchz {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(env_wchz, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::env_wchz!($ty, $($input)*)
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(env_wchz, $($input)*)
+    };
+}
+
+/// Optionally generate a UTF-16 or UTF-32 wide string from an environment
+/// variable at compile time.
+///
+/// The generated output takes the form of an `Option` of a slice of wide
+/// characters, which is `None` if the variable is not defined, as with
+/// [`option_env!`].
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// [`option_env!`]: https://doc.rust-lang.org/std/macro.option_env.html
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::option_env_wch;
+/// const VERSION: Option<&[u16]> = option_env_wch!(u16, "CARGO_PKG_VERSION");
+/// const MISSING: Option<&[u16]> = option_env_wch!(u16, "WCHAR_NOT_DEFINED");
+///
+/// assert!(VERSION.is_some());
+/// assert_eq!(MISSING, None);
+/// ```
+#[macro_export]
+macro_rules! option_env_wch {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(option_env_wch, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::option_env_wch!($ty, $($input)*)
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(option_env_wch, $($input)*)
+    };
+}
+
+/// Generate a UTF-16 or UTF-32 wide string from a UTF-8 encoded file.
+///
+/// The generated output takes the form of a slice of wide characters.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// The file path may be preceded by text transforms, see [`wch`] for details.
+///
+/// # Notes
+///
+/// Whilst this macro can be used for C-style nul-terminated wide strings, no
+/// validations are made about internal nul characters. If your strings need to
+/// be nul-terminated it is recommended to use [`include_wchz`].
+#[macro_export]
+macro_rules! include_wch {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(include_wch, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::include_wch!($ty, $($input)*)
+    };
+    ($string:literal) => {
+        $crate::__expand_platform_wchar!(include_wch, $string)
+    };
+}
+
+/// Generate a UTF-16 or UTF-32 wide string from a UTF-8 encoded file.
+///
+/// Validations are made that the given string does not contain nul characters.
+///
+/// The generated output takes the form of a slice of wide characters, with a
+/// nul-terminator as the last wide character.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// The file path may be preceded by text transforms, see [`wch`] for details.
+/// Validations are made after the transforms are applied.
+#[macro_export]
+macro_rules! include_wchz {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(include_wchz, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::include_wchz!($ty, $($input)*)
+    };
+    ($string:literal) => {
+        $crate::__expand_platform_wchar!(include_wchz, $string)
+    };
+}
+
+/// Generate a slice of UTF-16 or UTF-32 wide strings from the lines of a UTF-8
+/// encoded file.
+///
+/// Lines are separated by `\n` or `\r\n`, which are not included in the
+/// strings. The file path may be preceded by options, separated by commas:
+///
+/// - `skip_blank` skips lines that are empty or only contain whitespace.
+/// - `skip_comments = "prefix"` skips lines starting with the prefix, after
+///   any leading whitespace.
+///
+/// Options follow the character type, which can be given as `wchar_t` for the
+/// platform native type.
+///
+/// Relative paths are resolved from the directory of the calling source file,
+/// which requires the `unstable` feature. On stable, the path can be made
+/// absolute with `concat!(env!("CARGO_MANIFEST_DIR"), "/path/to/file")`, the
+/// only form of macro accepted in file paths.
+///
+/// The generated output takes the form of a slice of slices of wide characters,
+/// with one entry per line.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// # Examples
+///
+/// ```ignore
+/// use wchar::include_wch_lines;
+///
+/// static WORDS: &[&[u16]] = include_wch_lines!(u16, skip_blank, skip_comments = "#", "words.txt");
+/// ```
+#[macro_export]
+macro_rules! include_wch_lines {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(include_wch_lines, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::include_wch_lines!($ty, $($input)*)
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(include_wch_lines, $($input)*)
+    };
+}
+
+/// Generate a slice of C-style nul-terminated UTF-16 or UTF-32 wide strings
+/// from the lines of a UTF-8 encoded file.
+///
+/// Validations are made that the lines do not contain nul characters, and the
+/// line of any nul character is reported.
+///
+/// The generated output takes the form of a slice of slices of wide
+/// characters, with one entry per line and a nul-terminator as the last wide
+/// character of each entry.
+///
+/// See [`include_wch_lines`] for the supported options.
+#[macro_export]
+macro_rules! include_wchz_lines {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(include_wchz_lines, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::include_wchz_lines!($ty, $($input)*)
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(include_wchz_lines, $($input)*)
+    };
+}
+
+/// Generate a table of UTF-16 or UTF-32 wide strings from the UTF-8 encoded
+/// files of a directory.
+///
+/// The directory is walked recursively and its path is resolved the same way
+/// as [`include_wch_lines`]. If followed by `glob = "pattern"`, only the files whose
+/// path relative to the directory matches the pattern are included, where `?`
+/// matches any character and `*` any number of characters, except for `/`, and
+/// `**/` matches any number of directories.
+///
+/// The generated output takes the form of a [`WStrDir`], sorted by relative
+/// path, with `/` separating the components of the path.
+///
+/// Changes to the embedded files cause the crate to be rebuilt, but adding
+/// files to the directory does not. Symbolic links are skipped, and file names
+/// must be valid UTF-8.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// # Examples
+///
+/// ```ignore
+/// use wchar::{include_wch_dir, WStrDir};
+///
+/// static TEMPLATES: WStrDir<u16> = include_wch_dir!(u16, "templates", glob = "**/*.txt");
+///
+/// let welcome = TEMPLATES.get("mail/welcome.txt").unwrap();
+/// ```
+#[macro_export]
+macro_rules! include_wch_dir {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(include_wch_dir, $crate, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::include_wch_dir!($ty, $crate, $($input)*)
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(include_wch_dir, $crate, $($input)*)
+    };
+}
+
+/// Generate a module of UTF-16 or UTF-32 wide string constants from a
+/// localisation catalog file.
+///
+/// The catalog is either a gettext `.po` file or a `.properties` file of
+/// `key = value` lines, chosen by the file extension, and its path is resolved
+/// the same way as [`include_wch_lines`]. In `.po` files the `msgid` is the key
+/// and the `msgstr` the string, where the header, fuzzy entries and
+/// untranslated entries are skipped.
+///
+/// The macro expands to a module containing a constant for each key, named
+/// after the key in upper case with each run of other characters than ASCII
+/// letters and digits replaced by an underscore. The module also contains an
+/// `ENTRIES` static of the keys and strings sorted by key, and a
+/// `get(key: &str)` function to look strings up at runtime. Keys which would
+/// clash with another constant or with `ENTRIES` are reported at compile time.
+///
+/// If followed by `reference = "path"`, the keys are checked against those of
+/// another catalog, usually the one of the source language, and any missing or
+/// extra keys are reported at compile time.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// # Examples
+///
+/// ```ignore
+/// use wchar::{include_wch_catalog, wch};
+///
+/// include_wch_catalog!(u16, pub mod de, "locales/de.po", reference = "locales/en.po");
+///
+/// assert_eq!(de::OPEN_FILE, wch!(u16, "Datei öffnen"));
+/// assert_eq!(de::get("Open file"), Some(wch!(u16, "Datei öffnen")));
+/// ```
+#[macro_export]
+macro_rules! include_wch_catalog {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(include_wch_catalog, $($input)*);
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::include_wch_catalog!($ty, $($input)*);
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(include_wch_catalog, $($input)*);
+    };
+}
+
+/// Generate a pool of UTF-16 or UTF-32 wide strings, stored contiguously in a
+/// single static buffer.
+///
+/// The macro expands to a module containing a `usize` ID constant for each
+/// named string, the `BUFFER` static holding the encoded strings, and a
+/// [`WStrPool`] static named `POOL` to look strings up by ID. Storing strings
+/// this way keeps the number of statics and relocations to a minimum. Strings
+/// cannot be named `BUFFER` or `POOL`.
+///
+/// A nested `ranges` module holds an `(offset, len)` constant for each named
+/// string, locating it within `BUFFER` without a lookup through `POOL`.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::wch_pool;
+/// wch_pool! {
+///     u16,
+///     pub mod strings {
+///         HELLO = "Hello",
+///         WORLD = "World",
+///     }
+/// }
+///
+/// assert_eq!(strings::POOL.len(), 2);
+/// assert_eq!(strings::POOL.get(strings::HELLO), Some(&[0x0048, 0x0065, 0x006C, 0x006C, 0x006F][..]));
+/// assert_eq!(strings::BUFFER.len(), 10);
+///
+/// const WORLD: (usize, usize) = strings::ranges::WORLD;
+/// assert_eq!(WORLD, (5, 5));
+/// assert_eq!(&strings::BUFFER[WORLD.0..WORLD.0 + WORLD.1], &[0x0057, 0x006F, 0x0072, 0x006C, 0x0064][..]);
+/// ```
+#[macro_export]
+macro_rules! wch_pool {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wch_pool, $crate, $($input)*);
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::wch_pool!($ty, $crate, $($input)*);
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wch_pool, $crate, $($input)*);
+    };
+}
+
+/// Generate a pool of C-style nul-terminated UTF-16 or UTF-32 wide strings,
+/// stored contiguously in a single static buffer.
+///
+/// Validations are made that the given strings do not contain nul characters.
+/// Each string in the pool includes its nul-terminator.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used.
+///
+/// See [`wch_pool`] for details of the generated module.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::wchz_pool;
+/// wchz_pool! {
+///     u16,
+///     mod strings {
+///         FOO = "foo",
+///         BAR = "bar",
+///     }
+/// }
+///
+/// assert_eq!(strings::POOL.get(strings::BAR), Some(&[0x0062, 0x0061, 0x0072, 0x0000][..]));
+/// ```
+#[macro_export]
+macro_rules! wchz_pool {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wchz_pool, $crate, $($input)*);
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::wchz_pool!($ty, $crate, $($input)*);
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wchz_pool, $crate, $($input)*);
+    };
+}
+
+/// Generate a static map keyed by UTF-16 or UTF-32 wide strings.
+///
+/// The generated output takes the form of a [`WStrMap`], with the entries
+/// sorted by their encoded keys at compile time. Looking up a `&[T]` key is a
+/// binary search, without allocating or decoding. Values must be constant
+/// expressions.
+///
+/// The first argument is the output character type, if no type is specified the
+/// platform native `wchar_t` will be used. If followed by `ignore_ascii_case`,
+/// keys are compared ignoring ASCII case. The option follows the character
+/// type, which can be given as `wchar_t` for the platform native type.
+///
+/// Duplicate keys are rejected at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{wch, wch_map, WStrMap};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Switch {
+///     Help,
+///     Verbose,
+/// }
+///
+/// static SWITCHES: WStrMap<u16, Switch> = wch_map! {
+///     u16,
+///     ignore_ascii_case,
+///     "help" => Switch::Help,
+///     "verbose" => Switch::Verbose,
+/// };
+///
+/// assert_eq!(SWITCHES.get(wch!(u16, "help")), Some(&Switch::Help));
+/// assert_eq!(SWITCHES.get(wch!(u16, "VERBOSE")), Some(&Switch::Verbose));
+/// assert_eq!(SWITCHES.get(wch!(u16, "quiet")), None);
+/// ```
+#[macro_export]
+macro_rules! wch_map {
+    (wchar_t, $($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wch_map, $crate, $($input)*)
+    };
+    ($ty:ident, $($input:tt)*) => {
+        $crate::_impl::wch_map!($ty, $crate, $($input)*)
+    };
+    ($($input:tt)*) => {
+        $crate::__expand_platform_wchar!(wch_map, $crate, $($input)*)
+    };
+}
+
+/// Generate the `RT_STRING` resources of a Windows string table, as written in
+/// a `STRINGTABLE` statement of a resource script.
+///
+/// Each entry maps a `u16` string ID to a string literal. Windows stores the
+/// strings in blocks of 16, where string `id` is found in the block with
+/// resource ID `id / 16 + 1`. Each block is generated as binary resource data,
+/// holding its 16 strings as a little-endian `u16` length followed by the
+/// UTF-16LE code units, which can be linked as an `RT_STRING` resource.
+///
+/// Duplicate IDs are rejected at compile time.
+///
+/// The generated output takes the form of a [`StringTable`].
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{string_table, StringTable};
+///
+/// static STRINGS: StringTable = string_table! {
+///     1 => "Hi",
+///     17 => "",
+/// };
+///
+/// assert_eq!(STRINGS.len(), 2);
+/// assert_eq!(STRINGS.get(1), Some(&b"H\0i\0"[..]));
+///
+/// let block = STRINGS.block(1).unwrap();
+/// assert_eq!(&block[..8], b"\0\0\x02\0H\0i\0");
+/// assert_eq!(block.len(), 2 * 16 + 4);
+/// ```
+#[macro_export]
+macro_rules! string_table {
+    ($($input:tt)*) => {
+        $crate::_impl::string_table!($crate, $($input)*)
+    };
+}
+
+/// Format arguments into a UTF-16 or UTF-32 wide string at runtime.
+///
+/// The generated output takes the form of a [`WString`], encoded directly from
+/// the formatted text without an intermediate `String`.
+///
+/// The macro takes the same arguments as [`format!`], preceded by the output
+/// character type, one of `u16`, `u32`, `i16` or `i32`. If no type is specified
+/// the platform native `wchar_t` will be used.
+///
+/// Requires the `alloc` feature.
+///
+/// [`format!`]: https://doc.rust-lang.org/std/macro.format.html
+///
+/// # Notes
+///
+/// No validations are made about internal nul characters. If your strings need
+/// to be nul-terminated it is recommended to use [`wformatz`].
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::{wch, wformat};
+/// let name = "world";
+/// let wide = wformat!(u16, "Hello, {}! {:>3}", name, 42);
+///
+/// assert_eq!(wide, wch!(u16, "Hello, world!  42"));
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! wformat {
+    ($fmt:literal $($args:tt)*) => {
+        $crate::__wformat::<$crate::wchar_t>(::core::format_args!($fmt $($args)*), false)
+    };
+    ($ty:ident, $($args:tt)*) => {
+        $crate::__wformat::<$ty>(::core::format_args!($($args)*), false)
+    };
+}
+
+/// Format arguments into a C-style nul-terminated UTF-16 or UTF-32 wide string
+/// at runtime.
+///
+/// The generated output takes the form of a [`WString`], with a nul-terminator
+/// as the last wide character.
+///
+/// The macro takes the same arguments as [`format!`], preceded by the output
+/// character type, one of `u16`, `u32`, `i16` or `i32`. If no type is specified
+/// the platform native `wchar_t` will be used.
+///
+/// Requires the `alloc` feature.
+///
+/// [`format!`]: https://doc.rust-lang.org/std/macro.format.html
+///
+/// # Panics
+///
+/// As the text is only known at runtime, it is validated when formatted, and
+/// the macro panics if it contains nul characters.
+///
+/// # Examples
+///
+/// ```
+/// # use wchar::{wchz, wformatz};
+/// let wide = wformatz!(u32, "{}-{}", "foo", 1);
+///
+/// assert_eq!(wide, wchz!(u32, "foo-1"));
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! wformatz {
+    ($fmt:literal $($args:tt)*) => {
+        $crate::__wformat::<$crate::wchar_t>(::core::format_args!($fmt $($args)*), true)
+    };
+    ($ty:ident, $($args:tt)*) => {
+        $crate::__wformat::<$ty>(::core::format_args!($($args)*), true)
     };
 }
@@ -0,0 +1,115 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::WideChar;
+
+/// A static map keyed by wide strings.
+///
+/// Maps are usually generated with the [`wch_map`] macro, which sorts the
+/// entries at compile time so that lookups are a binary search over the
+/// encoded keys, without allocating or decoding.
+///
+/// [`wch_map`]: crate::wch_map
+pub struct WStrMap<T: 'static, V: 'static> {
+    entries: &'static [(&'static [T], V)],
+    ignore_ascii_case: bool,
+}
+
+impl<T, V> WStrMap<T, V> {
+    /// Creates a map from a slice of entries, sorted by key.
+    ///
+    /// If `ignore_ascii_case` is `true` the entries must be sorted by their
+    /// keys converted to ASCII lower case, and must not contain keys that only
+    /// differ in ASCII case.
+    ///
+    /// Lookups will not find entries if the slice is not correctly sorted.
+    pub const fn from_sorted_entries(
+        entries: &'static [(&'static [T], V)],
+        ignore_ascii_case: bool,
+    ) -> Self {
+        WStrMap {
+            entries,
+            ignore_ascii_case,
+        }
+    }
+
+    /// Returns `true` if the map ignores ASCII case when comparing keys.
+    pub const fn ignores_ascii_case(&self) -> bool {
+        self.ignore_ascii_case
+    }
+
+    /// Returns the number of entries in the map.
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the entries of the map, sorted by key.
+    pub const fn entries(&self) -> &'static [(&'static [T], V)] {
+        self.entries
+    }
+
+    /// Returns an iterator over the entries of the map, sorted by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static [T], &'static V)> + 'static {
+        self.entries.iter().map(|(k, v)| (*k, v))
+    }
+}
+
+impl<T: WideChar, V> WStrMap<T, V> {
+    /// Returns the value corresponding to the key.
+    pub fn get(&self, key: &[T]) -> Option<&'static V> {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    /// Returns the key-value pair corresponding to the key.
+    ///
+    /// The returned key is the key as written in the map, which may differ in
+    /// ASCII case from the given key if the map ignores ASCII case.
+    pub fn get_key_value(&self, key: &[T]) -> Option<(&'static [T], &'static V)> {
+        let entries = self.entries;
+
+        match entries.binary_search_by(|(k, _)| self.cmp_keys(k, key)) {
+            Ok(i) => {
+                let (k, v) = &entries[i];
+                Some((k, v))
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// Returns `true` if the map contains a value for the key.
+    pub fn contains_key(&self, key: &[T]) -> bool {
+        self.get_key_value(key).is_some()
+    }
+
+    fn cmp_keys(&self, a: &[T], b: &[T]) -> Ordering {
+        if self.ignore_ascii_case {
+            let a = a.iter().map(|c| c.to_ascii_lowercase());
+            let b = b.iter().map(|c| c.to_ascii_lowercase());
+
+            a.cmp(b)
+        } else {
+            a.cmp(b)
+        }
+    }
+}
+
+impl<T, V> Clone for WStrMap<T, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, V> Copy for WStrMap<T, V> {}
+
+impl<T: fmt::Debug, V: fmt::Debug> fmt::Debug for WStrMap<T, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries(self.entries.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
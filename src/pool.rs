@@ -0,0 +1,70 @@
+use core::fmt;
+
+/// A table of wide strings stored contiguously in a single static buffer.
+///
+/// Pools are usually generated with the [`wch_pool`] and [`wchz_pool`]
+/// macros, which assign each string a numeric ID that can be looked up at
+/// runtime.
+///
+/// [`wch_pool`]: crate::wch_pool
+/// [`wchz_pool`]: crate::wchz_pool
+pub struct WStrPool<T: 'static> {
+    buffer: &'static [T],
+    offsets: &'static [usize],
+}
+
+impl<T> WStrPool<T> {
+    /// Creates a pool from a buffer and the offsets of the strings within it.
+    ///
+    /// The string with ID `n` spans `buffer[offsets[n]..offsets[n + 1]]`, so
+    /// `offsets` should contain one more element than there are strings.
+    pub const fn from_raw_parts(buffer: &'static [T], offsets: &'static [usize]) -> Self {
+        WStrPool { buffer, offsets }
+    }
+
+    /// Returns the string with the given ID, or `None` if it is out of bounds.
+    pub fn get(&self, id: usize) -> Option<&'static [T]> {
+        let start = *self.offsets.get(id)?;
+        let end = *self.offsets.get(id + 1)?;
+
+        self.buffer.get(start..end)
+    }
+
+    /// Returns the number of strings in the pool.
+    pub const fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Returns `true` if the pool contains no strings.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the buffer containing every string in the pool.
+    pub const fn buffer(&self) -> &'static [T] {
+        self.buffer
+    }
+
+    /// Returns an iterator over the strings in the pool, in order of ID.
+    pub fn iter(&self) -> impl Iterator<Item = &'static [T]> + 'static {
+        let buffer = self.buffer;
+
+        self.offsets
+            .windows(2)
+            .map(move |range| &buffer[range[0]..range[1]])
+    }
+}
+
+impl<T> Clone for WStrPool<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for WStrPool<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for WStrPool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
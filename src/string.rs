@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+
+use crate::WideChar;
+use crate::write::WVecWriter;
+
+/// An owned UTF-16 or UTF-32 wide string.
+///
+/// The string is a growable buffer of wide characters, as returned by the
+/// [`wformat`] and [`wformatz`] macros. It derefs to the same slices of wide
+/// characters produced by the other macros, and compares equal to them. A
+/// pointer for FFI is given by [`as_ptr`](slice::as_ptr).
+///
+/// Text can be appended with [`push_str`](WString::push_str), or formatted
+/// onto the end of the string through its [`fmt::Write`] implementation.
+///
+/// Requires the `alloc` feature.
+///
+/// [`wformat`]: crate::wformat
+/// [`wformatz`]: crate::wformatz
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::Write;
+/// use wchar::{wch, WString};
+///
+/// let mut s = WString::<u16>::new();
+/// s.push_str("foo");
+/// write!(s, "-{}", 42).unwrap();
+///
+/// assert_eq!(s, wch!(u16, "foo-42"));
+/// assert_eq!(s.into_vec(), wch!(u16, "foo-42"));
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WString<T> {
+    vec: Vec<T>,
+}
+
+impl<T> WString<T> {
+    /// Creates an empty string.
+    pub const fn new() -> Self {
+        WString { vec: Vec::new() }
+    }
+
+    /// Creates a string from a `Vec` of wide characters, without validating
+    /// or copying them.
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        WString { vec }
+    }
+
+    /// Consumes the string, returning its wide characters.
+    pub fn into_vec(self) -> Vec<T> {
+        self.vec
+    }
+
+    /// Returns the wide characters of the string.
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T: WideChar> WString<T> {
+    /// Encodes the text onto the end of the string.
+    pub fn push_str(&mut self, s: &str) {
+        fmt::Write::write_str(self, s).expect("encoding into a `Vec` cannot fail");
+    }
+}
+
+impl<T> Deref for WString<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T> AsRef<[T]> for WString<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T> From<Vec<T>> for WString<T> {
+    fn from(vec: Vec<T>) -> Self {
+        WString { vec }
+    }
+}
+
+impl<T> From<WString<T>> for Vec<T> {
+    fn from(s: WString<T>) -> Self {
+        s.vec
+    }
+}
+
+impl<T: WideChar> fmt::Write for WString<T> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        fmt::Write::write_str(&mut WVecWriter::new(&mut self.vec), s)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        fmt::Write::write_char(&mut WVecWriter::new(&mut self.vec), c)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for WString<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.vec.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for WString<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.vec[..] == *other
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for WString<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.vec[..] == **other
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for WString<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.vec[..] == other[..]
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<&[T; N]> for WString<T> {
+    fn eq(&self, other: &&[T; N]) -> bool {
+        self.vec[..] == other[..]
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for WString<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.vec == *other
+    }
+}
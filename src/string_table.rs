@@ -0,0 +1,85 @@
+use core::fmt;
+
+/// A Windows string table, stored as the binary data of its `RT_STRING`
+/// resources.
+///
+/// Windows stores a string table in blocks of 16 strings, where string `id` is
+/// found in the block with resource ID `id / 16 + 1`. Each block holds the 16
+/// strings in order, each as a little-endian `u16` length followed by that many
+/// UTF-16LE code units, with missing strings having a length of zero.
+///
+/// String tables are usually generated with the [`string_table`] macro.
+///
+/// [`string_table`]: crate::string_table
+#[derive(Clone, Copy)]
+pub struct StringTable {
+    blocks: &'static [(u16, &'static [u8])],
+}
+
+impl StringTable {
+    /// Creates a string table from its blocks, which must be sorted by resource
+    /// ID without duplicates for lookups to work.
+    pub const fn from_sorted_blocks(blocks: &'static [(u16, &'static [u8])]) -> Self {
+        StringTable { blocks }
+    }
+
+    /// Returns the data of the block with the given resource ID, or `None` if
+    /// the table has no strings in that block.
+    pub fn block(&self, id: u16) -> Option<&'static [u8]> {
+        let index = self
+            .blocks
+            .binary_search_by_key(&id, |&(block_id, _)| block_id)
+            .ok()?;
+
+        Some(self.blocks[index].1)
+    }
+
+    /// Returns an iterator over the resource IDs and data of the blocks, in
+    /// order of resource ID.
+    pub fn blocks(&self) -> impl Iterator<Item = (u16, &'static [u8])> + 'static {
+        self.blocks.iter().copied()
+    }
+
+    /// Returns the UTF-16LE encoded bytes of the string with the given ID, or
+    /// `None` if its block is missing.
+    ///
+    /// As in Windows, a string missing from a block that is present is the
+    /// same as an empty string.
+    pub fn get(&self, id: u16) -> Option<&'static [u8]> {
+        let mut data = self.block(id / 16 + 1)?;
+
+        for _ in 0..id % 16 {
+            data = split_string(data)?.1;
+        }
+
+        Some(split_string(data)?.0)
+    }
+
+    /// Returns the number of blocks in the table.
+    pub const fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Returns `true` if the table contains no blocks.
+    pub const fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+}
+
+// Splits the first length-prefixed string from the data of a block.
+fn split_string(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let len = u16::from_le_bytes([*data.first()?, *data.get(1)?]) as usize;
+    let data = &data[2..];
+
+    if len * 2 > data.len() {
+        return None;
+    }
+
+    Some(data.split_at(len * 2))
+}
+
+impl fmt::Debug for StringTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.blocks()).finish()
+    }
+}
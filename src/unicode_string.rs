@@ -0,0 +1,86 @@
+use core::fmt;
+use core::slice;
+
+/// A static string in the layout of the NT `UNICODE_STRING` structure.
+///
+/// The lengths are counted in bytes and do not include a nul-terminator, as
+/// the buffer is not nul-terminated.
+///
+/// Strings are usually generated with the [`unicode_string`] macro, which
+/// computes the lengths at compile time. As the buffer is static and
+/// immutable, the string must never be passed to APIs that write to or free
+/// the buffer, such as `RtlFreeUnicodeString`.
+///
+/// [`unicode_string`]: crate::unicode_string
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct UnicodeString {
+    length: u16,
+    maximum_length: u16,
+    buffer: *const u16,
+}
+
+// SAFETY: The buffer is an immutable `'static` slice.
+unsafe impl Send for UnicodeString {}
+unsafe impl Sync for UnicodeString {}
+
+impl UnicodeString {
+    /// Creates a string from static UTF-16 code units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length in bytes does not fit in a `u16`.
+    pub const fn from_units(units: &'static [u16]) -> Self {
+        assert!(
+            units.len() <= (u16::MAX / 2) as usize,
+            "`UNICODE_STRING` length must not exceed `u16::MAX` bytes"
+        );
+
+        let length = units.len() as u16 * 2;
+
+        UnicodeString {
+            length,
+            maximum_length: length,
+            buffer: units.as_ptr(),
+        }
+    }
+
+    /// Returns the `Length` field, the length of the string in bytes.
+    pub const fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// Returns the `MaximumLength` field, the size of the buffer in bytes.
+    pub const fn maximum_length(&self) -> u16 {
+        self.maximum_length
+    }
+
+    /// Returns the `Buffer` field, a pointer to the code units of the string.
+    pub const fn as_ptr(&self) -> *const u16 {
+        self.buffer
+    }
+
+    /// Returns the length of the string in UTF-16 code units.
+    pub const fn len(&self) -> usize {
+        self.length as usize / 2
+    }
+
+    /// Returns `true` if the string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the code units of the string.
+    pub fn as_slice(&self) -> &'static [u16] {
+        // SAFETY: The buffer and length were taken from a `'static` slice.
+        unsafe { slice::from_raw_parts(self.buffer, self.len()) }
+    }
+}
+
+impl fmt::Debug for UnicodeString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("UnicodeString")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
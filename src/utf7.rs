@@ -0,0 +1,364 @@
+use core::fmt;
+use core::iter::Fuse;
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encode UTF-16 code units as UTF-7, as specified by RFC 2152.
+///
+/// Only the directly encoded characters of RFC 2152 and whitespace are written
+/// as themselves, the optional direct characters are base64 encoded.
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{encode_utf7, utf7, wch};
+///
+/// let bytes: Vec<u8> = encode_utf7(wch!(u16, "Hi Mom -☺-!").iter().copied()).collect();
+/// assert_eq!(bytes, utf7!("Hi Mom -☺-!"));
+/// ```
+pub fn encode_utf7<I: IntoIterator<Item = u16>>(units: I) -> EncodeUtf7<I::IntoIter> {
+    EncodeUtf7::new(units.into_iter(), false)
+}
+
+/// Encode UTF-16 code units as IMAP's modified UTF-7, as specified by RFC 3501
+/// for mailbox names.
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{encode_imap_utf7, imap_utf7, wch};
+///
+/// let bytes: Vec<u8> = encode_imap_utf7(wch!(u16, "~peter/mail/台北/日本語").iter().copied())
+///     .collect();
+/// assert_eq!(bytes, imap_utf7!("~peter/mail/台北/日本語"));
+/// ```
+pub fn encode_imap_utf7<I: IntoIterator<Item = u16>>(units: I) -> EncodeUtf7<I::IntoIter> {
+    EncodeUtf7::new(units.into_iter(), true)
+}
+
+/// Decode UTF-7 bytes into UTF-16 code units.
+///
+/// Decoding stops after the first error. Base64 runs are validated strictly, so
+/// a run must encode whole code units and leave any padding bits as zero.
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{decode_utf7, utf7, wch};
+///
+/// let units: Result<Vec<u16>, _> = decode_utf7(utf7!("1 + 1 ≠ 3")).collect();
+/// assert_eq!(units.unwrap(), wch!(u16, "1 + 1 ≠ 3"));
+///
+/// assert!(decode_utf7(b"+AOl-").any(|unit| unit.is_err()));
+/// ```
+pub fn decode_utf7(bytes: &[u8]) -> DecodeUtf7<'_> {
+    DecodeUtf7::new(bytes, false)
+}
+
+/// Decode IMAP's modified UTF-7 bytes into UTF-16 code units.
+///
+/// Decoding stops after the first error. Only the canonical encoding is
+/// accepted, so printable ASCII must not be base64 encoded, base64 runs must be
+/// terminated by `-` and must not be adjacent.
+///
+/// # Examples
+///
+/// ```
+/// use wchar::{decode_imap_utf7, imap_utf7, wch};
+///
+/// let units: Result<Vec<u16>, _> = decode_imap_utf7(imap_utf7!("Entwürfe & Notizen")).collect();
+/// assert_eq!(units.unwrap(), wch!(u16, "Entwürfe & Notizen"));
+///
+/// assert!(decode_imap_utf7(b"&AGE-").any(|unit| unit.is_err()));
+/// ```
+pub fn decode_imap_utf7(bytes: &[u8]) -> DecodeUtf7<'_> {
+    DecodeUtf7::new(bytes, true)
+}
+
+// The characters written as themselves by the encoder.
+fn is_direct(unit: u16, imap: bool) -> bool {
+    if imap {
+        return (0x20..=0x7E).contains(&unit);
+    }
+
+    match unit {
+        0x09 | 0x0A | 0x0D | 0x20 | 0x2B => true,
+        0x30..=0x39 | 0x41..=0x5A | 0x61..=0x7A => true,
+        _ => unit < 0x80 && b"'(),-./:?".contains(&(unit as u8)),
+    }
+}
+
+// The characters accepted as themselves by the decoder, which for UTF-7 also
+// includes the optional direct characters of RFC 2152.
+fn is_direct_decode(b: u8, imap: bool) -> bool {
+    is_direct(b.into(), imap) || !imap && b"!\"#$%&*;<=>@[]^_`{|}".contains(&b)
+}
+
+fn is_base64(unit: u16) -> bool {
+    unit < 0x80 && BASE64.contains(&(unit as u8))
+}
+
+fn encode_base64(bits: u32, imap: bool) -> u8 {
+    match BASE64[(bits & 0x3F) as usize] {
+        b'/' if imap => b',',
+        b => b,
+    }
+}
+
+fn decode_base64(b: u8, imap: bool) -> Option<u32> {
+    let value = match b {
+        b'A'..=b'Z' => b - b'A',
+        b'a'..=b'z' => b - b'a' + 26,
+        b'0'..=b'9' => b - b'0' + 52,
+        b'+' => 62,
+        b',' if imap => 63,
+        b'/' if !imap => 63,
+        _ => return None,
+    };
+
+    Some(value.into())
+}
+
+/// An iterator over the UTF-7 or modified UTF-7 encoded bytes of UTF-16 code
+/// units.
+///
+/// Created by [`encode_utf7`] and [`encode_imap_utf7`].
+#[derive(Clone, Debug)]
+pub struct EncodeUtf7<I> {
+    units: Fuse<I>,
+    imap: bool,
+    // The pending bits of the current base64 run, if one is open.
+    run: Option<(u32, u32)>,
+    buf: [u8; 4],
+    pos: usize,
+    len: usize,
+}
+
+impl<I: Iterator> EncodeUtf7<I> {
+    fn new(units: I, imap: bool) -> Self {
+        EncodeUtf7 {
+            units: units.fuse(),
+            imap,
+            run: None,
+            buf: [0; 4],
+            pos: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<I> EncodeUtf7<I> {
+    fn push(&mut self, b: u8) {
+        self.buf[self.len] = b;
+        self.len += 1;
+    }
+
+    // Writes out the pending bits and terminates the current run.
+    fn close_run(&mut self, next: Option<u16>) {
+        if let Some((bits, len)) = self.run.take() {
+            if len > 0 {
+                self.push(encode_base64(bits << (6 - len), self.imap));
+            }
+
+            // The terminator is optional in UTF-7, unless the next character
+            // would be read as part of the run.
+            let needs_terminator = match next {
+                Some(unit) => self.imap || is_base64(unit) || unit == u16::from(b'-'),
+                None => true,
+            };
+            if needs_terminator {
+                self.push(b'-');
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = u16>> Iterator for EncodeUtf7<I> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.pos == self.len {
+            self.pos = 0;
+            self.len = 0;
+
+            match self.units.next() {
+                Some(unit) if is_direct(unit, self.imap) => {
+                    self.close_run(Some(unit));
+
+                    let shift = if self.imap { b'&' } else { b'+' };
+                    self.push(unit as u8);
+                    if unit == u16::from(shift) {
+                        self.push(b'-');
+                    }
+                }
+                Some(unit) => {
+                    let (mut bits, mut len) = match self.run {
+                        Some(run) => run,
+                        None => {
+                            self.push(if self.imap { b'&' } else { b'+' });
+                            (0, 0)
+                        }
+                    };
+
+                    bits = bits << 16 | u32::from(unit);
+                    len += 16;
+                    while len >= 6 {
+                        len -= 6;
+                        self.push(encode_base64(bits >> len, self.imap));
+                    }
+                    bits &= (1 << len) - 1;
+
+                    self.run = Some((bits, len));
+                }
+                None => self.close_run(None),
+            }
+
+            if self.len == 0 {
+                return None;
+            }
+        }
+
+        let b = self.buf[self.pos];
+        self.pos += 1;
+        Some(b)
+    }
+}
+
+/// An iterator over the UTF-16 code units decoded from UTF-7 or modified UTF-7
+/// bytes.
+///
+/// Created by [`decode_utf7`] and [`decode_imap_utf7`].
+#[derive(Clone, Debug)]
+pub struct DecodeUtf7<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    imap: bool,
+    // The pending bits of the current base64 run, if one is open.
+    run: Option<(u32, u32)>,
+    // Whether the previous base64 run ended at the current position.
+    after_run: bool,
+}
+
+impl<'a> DecodeUtf7<'a> {
+    fn new(bytes: &'a [u8], imap: bool) -> Self {
+        DecodeUtf7 {
+            bytes,
+            pos: 0,
+            imap,
+            run: None,
+            after_run: false,
+        }
+    }
+
+    fn decode_unit(&mut self) -> Result<Option<u16>, ()> {
+        let shift = if self.imap { b'&' } else { b'+' };
+
+        loop {
+            let (mut bits, mut len) = match self.run {
+                Some(run) => run,
+                None => {
+                    let b = match self.bytes.get(self.pos) {
+                        Some(&b) => b,
+                        None => return Ok(None),
+                    };
+
+                    if b != shift {
+                        if !is_direct_decode(b, self.imap) {
+                            return Err(());
+                        }
+                        self.pos += 1;
+                        self.after_run = false;
+                        return Ok(Some(b.into()));
+                    }
+
+                    match self.bytes.get(self.pos + 1) {
+                        Some(b'-') => {
+                            self.pos += 2;
+                            self.after_run = false;
+                            return Ok(Some(shift.into()));
+                        }
+                        // Adjacent runs must be merged into one.
+                        Some(_) if self.imap && self.after_run => return Err(()),
+                        Some(&b) if decode_base64(b, self.imap).is_some() => {
+                            self.pos += 1;
+                            (0, 0)
+                        }
+                        _ => return Err(()),
+                    }
+                }
+            };
+
+            if len >= 16 {
+                len -= 16;
+                let unit = (bits >> len) as u16;
+                bits &= (1 << len) - 1;
+
+                // Printable ASCII must represent itself in modified UTF-7.
+                if self.imap && is_direct(unit, true) {
+                    return Err(());
+                }
+
+                self.run = Some((bits, len));
+                return Ok(Some(unit));
+            }
+
+            let next = self.bytes.get(self.pos).copied();
+            match next.and_then(|b| decode_base64(b, self.imap)) {
+                Some(value) => {
+                    self.pos += 1;
+                    self.run = Some((bits << 6 | value, len + 6));
+                }
+                None => {
+                    // The run must end on a code unit, with zero padding bits.
+                    if len >= 6 || bits != 0 {
+                        return Err(());
+                    }
+
+                    match next {
+                        Some(b'-') => self.pos += 1,
+                        _ if self.imap => return Err(()),
+                        _ => {}
+                    }
+
+                    self.run = None;
+                    self.after_run = true;
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for DecodeUtf7<'_> {
+    type Item = Result<u16, Utf7Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.decode_unit() {
+            Ok(unit) => unit.map(Ok),
+            Err(()) => {
+                let err = Utf7Error { index: self.pos };
+                self.pos = self.bytes.len();
+                self.run = None;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// An error returned when decoding invalid UTF-7 or modified UTF-7.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Utf7Error {
+    index: usize,
+}
+
+impl Utf7Error {
+    /// Returns the index in the input at which the error was found.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for Utf7Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid UTF-7 at index {}", self.index)
+    }
+}
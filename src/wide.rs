@@ -0,0 +1,92 @@
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for i16 {}
+    impl Sealed for i32 {}
+}
+
+/// A wide character type, one of `u16`, `u32`, `i16` or `i32`.
+///
+/// `u16` and `i16` are UTF-16 code units, `u32` and `i32` are UTF-32 code
+/// units.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait WideChar: Copy + Ord + private::Sealed {
+    /// The nul wide character.
+    const NUL: Self;
+
+    /// Returns the number of wide characters needed to encode `c`.
+    fn char_len(c: char) -> usize;
+
+    /// Encodes `c` into `dst`, returning the subslice containing the encoded
+    /// wide characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is shorter than [`char_len`](WideChar::char_len).
+    fn encode_char(c: char, dst: &mut [Self]) -> &mut [Self];
+
+    /// Converts the character to its ASCII lower case equivalent.
+    ///
+    /// Characters outside of `'A'..='Z'` are returned unchanged.
+    fn to_ascii_lowercase(self) -> Self;
+
+    // The width of a code unit in bits.
+    #[doc(hidden)]
+    const BITS: u32;
+
+    // Returns the bits of the code unit, reinterpreting signed types.
+    #[doc(hidden)]
+    fn to_bits(self) -> u32;
+}
+
+macro_rules! impl_wide_char {
+    ($($ty:ident as $unsigned:ident)*; $char_len:expr, $encode_char:expr) => {
+        $(
+            impl WideChar for $ty {
+                const NUL: Self = 0;
+
+                const BITS: u32 = $unsigned::BITS;
+
+                #[inline]
+                fn char_len(c: char) -> usize {
+                    $char_len(c)
+                }
+
+                #[inline]
+                fn encode_char(c: char, dst: &mut [Self]) -> &mut [Self] {
+                    let len = Self::char_len(c);
+                    let dst = &mut dst[..len];
+                    $encode_char(c, &mut *dst);
+                    dst
+                }
+
+                #[inline]
+                fn to_ascii_lowercase(self) -> Self {
+                    match self {
+                        0x41..=0x5A => self + 0x20,
+                        _ => self,
+                    }
+                }
+
+                #[inline]
+                fn to_bits(self) -> u32 {
+                    self as $unsigned as u32
+                }
+            }
+        )*
+    };
+}
+
+impl_wide_char!(u16 as u16 i16 as u16; char::len_utf16, |c: char, dst: &mut [Self]| {
+    let mut buf = [0; 2];
+    for (dst, unit) in dst.iter_mut().zip(c.encode_utf16(&mut buf)) {
+        *dst = *unit as Self;
+    }
+});
+
+impl_wide_char!(u32 as u32 i32 as u32; |_| 1, |c: char, dst: &mut [Self]| {
+    dst[0] = c as Self;
+});
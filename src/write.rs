@@ -0,0 +1,179 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::string::WString;
+
+use crate::WideChar;
+
+/// A [`fmt::Write`] sink that encodes into a fixed size buffer of wide
+/// characters.
+///
+/// Writing returns an error if the buffer does not have room for the text, in
+/// which case only the characters that fit are written.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::Write;
+/// use wchar::{wch, WSliceWriter};
+///
+/// let mut buf = [0u16; 16];
+/// let mut w = WSliceWriter::new(&mut buf);
+///
+/// write!(w, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+/// assert_eq!(w.as_slice(), wch!(u16, "1 + 2 = 3"));
+///
+/// assert!(write!(w, "{}", "too long to fit").is_err());
+/// ```
+pub struct WSliceWriter<'a, T> {
+    buf: &'a mut [T],
+    len: usize,
+}
+
+impl<'a, T: WideChar> WSliceWriter<'a, T> {
+    /// Creates a writer that encodes into the given buffer.
+    pub fn new(buf: &'a mut [T]) -> Self {
+        WSliceWriter { buf, len: 0 }
+    }
+
+    /// Returns the number of wide characters written.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing has been written.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the total number of wide characters the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the wide characters written so far.
+    pub fn as_slice(&self) -> &[T] {
+        &self.buf[..self.len]
+    }
+
+    /// Consumes the writer, returning the wide characters written.
+    pub fn into_slice(self) -> &'a mut [T] {
+        &mut self.buf[..self.len]
+    }
+
+    /// Writes a nul-terminator, returning an error if there is no room for it.
+    pub fn write_nul(&mut self) -> fmt::Result {
+        match self.buf.get_mut(self.len) {
+            Some(dst) => {
+                *dst = T::NUL;
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(fmt::Error),
+        }
+    }
+}
+
+impl<T: WideChar> fmt::Write for WSliceWriter<'_, T> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        let dst = &mut self.buf[self.len..];
+        if dst.len() < T::char_len(c) {
+            return Err(fmt::Error);
+        }
+
+        self.len += T::encode_char(c, dst).len();
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for WSliceWriter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(&self.buf[..self.len]).finish()
+    }
+}
+
+/// A [`fmt::Write`] sink that encodes onto the end of a `Vec` of wide
+/// characters.
+///
+/// This is the sink behind [`WString`], for formatting onto a `Vec` that is
+/// owned elsewhere.
+///
+/// [`WString`]: crate::WString
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::Write;
+/// use wchar::{wch, WVecWriter};
+///
+/// let mut v: Vec<u32> = Vec::new();
+/// write!(WVecWriter::new(&mut v), "{}-{}", "foo", 42).unwrap();
+///
+/// assert_eq!(v, wch!(u32, "foo-42"));
+/// ```
+#[cfg(feature = "alloc")]
+pub struct WVecWriter<'a, T> {
+    vec: &'a mut Vec<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: WideChar> WVecWriter<'a, T> {
+    /// Creates a writer that encodes onto the end of the given `Vec`.
+    pub fn new(vec: &'a mut Vec<T>) -> Self {
+        WVecWriter { vec }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: WideChar> fmt::Write for WVecWriter<'_, T> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.vec.reserve(s.len());
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        let mut buf = [T::NUL; 2];
+        self.vec.extend_from_slice(T::encode_char(c, &mut buf));
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: fmt::Debug> fmt::Debug for WVecWriter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.vec.iter()).finish()
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+pub fn __wformat<T: WideChar>(args: fmt::Arguments, nul_terminated: bool) -> WString<T> {
+    use core::fmt::Write;
+
+    let mut vec = Vec::new();
+    WVecWriter::new(&mut vec)
+        .write_fmt(args)
+        .expect("a formatting trait implementation returned an error");
+
+    if nul_terminated {
+        assert!(
+            !vec.contains(&T::NUL),
+            "formatted C-style string cannot contain nul characters"
+        );
+        vec.push(T::NUL);
+    }
+    WString::from_vec(vec)
+}
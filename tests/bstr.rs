@@ -0,0 +1,78 @@
+use core::mem;
+
+use wchar::{Bstr, bstr, const_utf16z, wch, wchz};
+
+// Check we can use the macro to declare constants.
+const NAME: &Bstr = bstr!("Name");
+static STATIC: &Bstr = bstr!("static");
+
+#[test]
+fn layout() {
+    assert_eq!(mem::align_of_val(NAME), 4);
+    assert_eq!(NAME.as_ptr() as usize % 4, 0);
+    assert_eq!(
+        NAME.as_ptr() as usize - NAME as *const Bstr as *const u8 as usize,
+        4
+    );
+
+    assert_eq!(NAME.as_bytes(), b"\x08\0\0\0N\0a\0m\0e\0\0\0",);
+
+    // The prefix and terminator can be read through the `BSTR` pointer.
+    unsafe {
+        let ptr = NAME.as_ptr();
+        assert_eq!(*(ptr as *const u32).sub(1), 8);
+        assert_eq!(*ptr, 0x004E);
+        assert_eq!(*ptr.add(4), 0);
+    }
+}
+
+#[test]
+fn data() {
+    assert_eq!(NAME.len(), 4);
+    assert_eq!(NAME.byte_len(), 8);
+    assert_eq!(NAME.as_slice(), wch!(u16, "Name"));
+    assert_eq!(NAME.as_slice_with_nul(), wchz!(u16, "Name"));
+
+    assert_eq!(STATIC.as_slice(), wch!(u16, "static"));
+    assert_eq!(bstr!("🦀").as_slice(), wch!(u16, "🦀"));
+    assert_eq!(bstr!("🦀").byte_len(), 4);
+}
+
+#[test]
+fn empty() {
+    let empty = bstr!("");
+
+    assert!(empty.is_empty());
+    assert_eq!(empty.byte_len(), 0);
+    assert_eq!(empty.as_slice_with_nul(), [0]);
+    assert_eq!(empty.as_bytes(), b"\0\0\0\0\0\0");
+}
+
+#[test]
+fn embedded_nul() {
+    let s = bstr!("a\0b");
+
+    assert_eq!(s.len(), 3);
+    assert_eq!(s.byte_len(), 6);
+    assert_eq!(s.as_slice_with_nul(), [0x0061, 0x0000, 0x0062, 0x0000]);
+}
+
+#[test]
+fn from_units() {
+    const UNITS: Bstr<[u16; 4]> = Bstr::from_units(const_utf16z("foo"));
+    const FOO: &Bstr = &UNITS;
+
+    assert_eq!(FOO.as_slice(), wch!(u16, "foo"));
+    assert_eq!(FOO.byte_len(), 6);
+}
+
+#[test]
+#[should_panic = "`BSTR` must be nul-terminated"]
+fn from_units_without_nul() {
+    let _ = Bstr::from_units([0x0061]);
+}
+
+#[test]
+fn debug() {
+    assert_eq!(format!("{:?}", bstr!("ab")), "Bstr([97, 98])");
+}
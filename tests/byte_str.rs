@@ -0,0 +1,37 @@
+use wchar::{wch, wchar_t, wchz};
+
+// Check we can use the macro to declare constants.
+const _: &[wchar_t] = wch!(wchar_t, latin1, b"const");
+const _: &[u16] = wch!(u16, cp1252, b"const");
+const _: &[u32] = wchz!(u32, latin1, b"const");
+const _: u16 = wch!(u16, cp1252, b'c');
+
+#[test]
+fn latin1() {
+    assert_eq!(wch!(u16, latin1, b"caf\xE9"), wch!(u16, "café"));
+    assert_eq!(wch!(u32, latin1, b"\xA3\xFF"), wch!(u32, "£ÿ"));
+    // C1 control characters are mapped as is.
+    assert_eq!(wch!(i16, latin1, b"\x80\x9F"), wch!(i16, "\u{80}\u{9F}"));
+    assert_eq!(wchz!(i32, latin1, b"caf\xE9"), wchz!(i32, "café"));
+
+    assert_eq!(wch!(u16, latin1, b'\xE9'), wch!(u16, 'é'));
+}
+
+#[test]
+fn cp1252() {
+    assert_eq!(wch!(u16, cp1252, b"\x80 5"), wch!(u16, "€ 5"));
+    assert_eq!(
+        wch!(u32, cp1252, b"\x93quoted\x94 \x96 \x85"),
+        wch!(u32, "\u{201C}quoted\u{201D} \u{2013} \u{2026}")
+    );
+    assert_eq!(wch!(u16, cp1252, b"caf\xE9"), wch!(u16, "café"));
+    assert_eq!(wchz!(u16, cp1252, b"\x8A\x9F"), wchz!(u16, "ŠŸ"));
+
+    assert_eq!(wch!(u16, cp1252, b'\x99'), wch!(u16, '™'));
+}
+
+#[test]
+fn with_transforms() {
+    assert_eq!(wch!(u16, cp1252, upper, b"caf\xE9"), wch!(u16, "CAFÉ"));
+    assert_eq!(wch!(u16, lower, latin1, b'\xC9'), wch!(u16, 'é'));
+}
@@ -0,0 +1,102 @@
+use wchar::{
+    cesu8, cesu8z, decode_cesu8, decode_mutf8, encode_cesu8, encode_mutf8, mutf8, mutf8z, wch,
+};
+
+// Check we can use the macro to declare constants.
+const _: &[u8] = cesu8!("const");
+const _: &[u8] = mutf8z!("const");
+
+fn decode(units: Result<Vec<u16>, wchar::Cesu8Error>) -> Vec<u16> {
+    units.unwrap()
+}
+
+#[test]
+fn ascii() {
+    assert_eq!(cesu8!("foo bar"), b"foo bar");
+    assert_eq!(mutf8!("foo bar"), b"foo bar");
+    assert_eq!(cesu8z!("foo"), b"foo\0");
+    assert_eq!(mutf8z!("foo"), b"foo\0");
+}
+
+#[test]
+fn bmp() {
+    assert_eq!(cesu8!("é€"), "é€".as_bytes());
+    assert_eq!(
+        mutf8!("\u{7FF}\u{800}\u{FFFF}"),
+        "\u{7FF}\u{800}\u{FFFF}".as_bytes()
+    );
+}
+
+#[test]
+fn supplementary() {
+    assert_eq!(cesu8!("🦀"), b"\xED\xA0\xBE\xED\xB6\x80");
+    assert_eq!(mutf8!("🦀"), b"\xED\xA0\xBE\xED\xB6\x80");
+    assert_eq!(mutf8z!("\u{10FFFF}"), b"\xED\xAF\xBF\xED\xBF\xBF\0");
+}
+
+#[test]
+fn nul() {
+    assert_eq!(cesu8!("a\0b"), b"a\0b");
+    assert_eq!(mutf8!("a\0b"), b"a\xC0\x80b");
+    assert_eq!(mutf8z!("\0"), b"\xC0\x80\0");
+}
+
+#[test]
+fn encode() {
+    let text = "a\0é€🦀";
+
+    let bytes: Vec<u8> = encode_cesu8(text.encode_utf16()).collect();
+    assert_eq!(bytes, cesu8!("a\0é€🦀"));
+
+    let bytes: Vec<u8> = encode_mutf8(text.encode_utf16()).collect();
+    assert_eq!(bytes, mutf8!("a\0é€🦀"));
+
+    // Lone surrogates are encoded as is.
+    let bytes: Vec<u8> = encode_mutf8([0xD800]).collect();
+    assert_eq!(bytes, b"\xED\xA0\x80");
+}
+
+#[test]
+fn decode_round_trip() {
+    assert_eq!(
+        decode(decode_cesu8(cesu8!("a\0é€🦀")).collect()),
+        wch!(u16, "a\0é€🦀")
+    );
+    assert_eq!(
+        decode(decode_mutf8(mutf8!("a\0é€🦀")).collect()),
+        wch!(u16, "a\0é€🦀")
+    );
+    assert_eq!(decode(decode_mutf8(b"\xED\xB0\x80").collect()), [0xDC00]);
+    assert!(decode_cesu8(b"").next().is_none());
+}
+
+#[test]
+fn decode_errors() {
+    fn error(result: Result<Vec<u16>, wchar::Cesu8Error>) -> usize {
+        result.unwrap_err().valid_up_to()
+    }
+
+    // Raw nul is only valid in CESU-8, `C0 80` only in modified UTF-8.
+    assert_eq!(error(decode_mutf8(b"ab\0").collect()), 2);
+    assert_eq!(error(decode_cesu8(b"a\xC0\x80").collect()), 1);
+
+    // Overlong encodings.
+    assert_eq!(error(decode_cesu8(b"\xC1\xBF").collect()), 0);
+    assert_eq!(error(decode_mutf8(b"a\xE0\x80\x80").collect()), 1);
+
+    // 4 byte UTF-8 sequences.
+    assert_eq!(error(decode_cesu8("🦀".as_bytes()).collect()), 0);
+
+    // Truncated and invalid continuation bytes.
+    assert_eq!(error(decode_cesu8(b"a\xED\xA0").collect()), 1);
+    assert_eq!(error(decode_mutf8(b"\xC3a").collect()), 0);
+    assert_eq!(error(decode_mutf8(b"\x80").collect()), 0);
+
+    let err = decode_cesu8(b"ab\xFF").nth(2).unwrap().unwrap_err();
+    assert_eq!(err.to_string(), "invalid byte sequence at index 2");
+
+    // Decoding stops after the first error.
+    let mut iter = decode_cesu8(b"\xFFa");
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+}
@@ -8,4 +8,7 @@ fn ui() {
     if cfg!(feature = "unstable") {
         t.compile_fail("tests/ui_unstable/*.rs");
     }
+    if cfg!(feature = "unicode-names") {
+        t.compile_fail("tests/ui_unicode_names/*.rs");
+    }
 }
@@ -0,0 +1,110 @@
+use wchar::{const_utf16, const_utf16z, const_utf32, const_utf32z, utf16_len, utf32_len};
+use wchar::{wch, wchz};
+
+macro_rules! test_const_encode {
+    ($s:literal) => {{
+        const S: &str = $s;
+
+        const UTF16: [u16; utf16_len(S)] = const_utf16(S);
+        const UTF16Z: [u16; utf16_len(S) + 1] = const_utf16z(S);
+        const UTF32: [u32; utf32_len(S)] = const_utf32(S);
+        const UTF32Z: [u32; utf32_len(S) + 1] = const_utf32z(S);
+
+        assert_eq!(&UTF16, wch!(u16, $s));
+        assert_eq!(&UTF16Z, wchz!(u16, $s));
+        assert_eq!(&UTF32, wch!(u32, $s));
+        assert_eq!(&UTF32Z, wchz!(u32, $s));
+    }};
+}
+
+const PKG_NAME: &str = env!("CARGO_PKG_NAME");
+const CONCAT: &str = concat!("foo", 42, '🦀', "bar");
+
+#[test]
+fn basic() {
+    test_const_encode!("foo");
+    test_const_encode!("bar");
+    test_const_encode!("foo bar");
+}
+
+#[test]
+fn complex() {
+    // Two, three and four byte UTF-8 sequences.
+    test_const_encode!("ß");
+    test_const_encode!("京");
+    test_const_encode!("٣");
+    test_const_encode!("𐐷");
+    test_const_encode!("\u{7F}\u{80}\u{7FF}\u{800}\u{FFFF}\u{10000}\u{10FFFF}");
+}
+
+#[test]
+fn emoji() {
+    test_const_encode!("🦀");
+    test_const_encode!("💖");
+    test_const_encode!("🇬🇧");
+}
+
+#[test]
+fn empty() {
+    test_const_encode!("");
+}
+
+#[test]
+fn lengths() {
+    assert_eq!(utf16_len("foo"), 3);
+    assert_eq!(utf16_len("🦀"), 2);
+    assert_eq!(utf16_len(""), 0);
+
+    assert_eq!(utf32_len("foo"), 3);
+    assert_eq!(utf32_len("🦀"), 1);
+    assert_eq!(utf32_len(""), 0);
+}
+
+#[test]
+fn nul_chars() {
+    const UTF16: [u16; 7] = const_utf16("foo\0bar");
+    const UTF32: [u32; 7] = const_utf32("foo\0bar");
+
+    assert_eq!(&UTF16, wch!(u16, "foo\0bar"));
+    assert_eq!(&UTF32, wch!(u32, "foo\0bar"));
+}
+
+#[test]
+fn env() {
+    const UTF16: [u16; utf16_len(PKG_NAME)] = const_utf16(PKG_NAME);
+    const UTF32Z: [u32; utf32_len(PKG_NAME) + 1] = const_utf32z(PKG_NAME);
+
+    assert_eq!(&UTF16, wch!(u16, "wchar"));
+    assert_eq!(&UTF32Z, wchz!(u32, "wchar"));
+}
+
+#[test]
+fn concat() {
+    const UTF16Z: [u16; utf16_len(CONCAT) + 1] = const_utf16z(CONCAT);
+    const UTF32: [u32; utf32_len(CONCAT)] = const_utf32(CONCAT);
+
+    assert_eq!(&UTF16Z, wchz!(u16, "foo42🦀bar"));
+    assert_eq!(&UTF32, wch!(u32, "foo42🦀bar"));
+}
+
+#[test]
+fn runtime() {
+    let s = String::from("foo🦀");
+
+    assert_eq!(const_utf16::<5>(&s), *wch!(u16, "foo🦀"));
+    assert_eq!(const_utf32z::<5>(&s), *wchz!(u32, "foo🦀"));
+}
+
+#[test]
+#[should_panic = "array length does not match the UTF-16 length of the string"]
+fn wrong_length() {
+    let s = String::from("foo");
+    const_utf16::<4>(&s);
+}
+
+#[test]
+#[should_panic = "C-style string cannot contain nul characters"]
+fn nul_chars_c() {
+    let s = String::from("foo\0");
+    const_utf32z::<5>(&s);
+}
@@ -0,0 +1,53 @@
+use wchar::{cp, cpz};
+
+// Check we can use the macro to declare constants.
+const _: &[u8] = cp!(1252, "const");
+const _: &[u8] = cp!(437, "const");
+const _: &[u8] = cpz!(850, "const");
+
+#[test]
+fn ascii() {
+    assert_eq!(cp!(1252, "foo bar"), b"foo bar");
+    assert_eq!(cp!(437, "foo bar"), b"foo bar");
+    assert_eq!(cp!(850, "foo bar"), b"foo bar");
+
+    let empty: &[u8] = cp!(1252, "");
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn cp1252() {
+    assert_eq!(cp!(1252, "café"), b"caf\xE9");
+    assert_eq!(
+        cp!(1252, "€‚ƒ„…†‡ˆ‰Š‹ŒŽ"),
+        b"\x80\x82\x83\x84\x85\x86\x87\x88\x89\x8A\x8B\x8C\x8E"
+    );
+    assert_eq!(
+        cp!(1252, "‘’“”•–—˜™š›œžŸ"),
+        b"\x91\x92\x93\x94\x95\x96\x97\x98\x99\x9A\x9B\x9C\x9E\x9F"
+    );
+    assert_eq!(cp!(1252, "\u{A0}ÿ"), b"\xA0\xFF");
+}
+
+#[test]
+fn cp437() {
+    assert_eq!(cp!(437, "Ç"), b"\x80");
+    assert_eq!(cp!(437, "╔═╗║╚╝"), b"\xC9\xCD\xBB\xBA\xC8\xBC");
+    assert_eq!(cp!(437, "αßπ∞"), b"\xE0\xE1\xE3\xEC");
+    assert_eq!(cp!(437, "\u{A0}"), b"\xFF");
+}
+
+#[test]
+fn cp850() {
+    assert_eq!(cp!(850, "Ç"), b"\x80");
+    assert_eq!(cp!(850, "øØ×"), b"\x9B\x9D\x9E");
+    assert_eq!(cp!(850, "ÁÂÀ©"), b"\xB5\xB6\xB7\xB8");
+    assert_eq!(cp!(850, "\u{A0}"), b"\xFF");
+}
+
+#[test]
+fn nul_terminated() {
+    assert_eq!(cpz!(1252, "café"), b"caf\xE9\0");
+    assert_eq!(cpz!(437, "─"), b"\xC4\0");
+    assert_eq!(cpz!(850, ""), b"\0");
+}
@@ -0,0 +1,51 @@
+use wchar::{env_wch, env_wchz, option_env_wch, wchar_t};
+
+macro_rules! test_env_wch {
+    ($name:literal) => {{
+        let value = env!($name);
+
+        let utf16: Vec<u16> = value.encode_utf16().collect();
+        let utf32: Vec<u32> = value.chars().map(|c| c as u32).collect();
+
+        assert_eq!(env_wch!(u16, $name), &utf16[..]);
+        assert_eq!(env_wch!(u32, $name), &utf32[..]);
+        assert_eq!(env_wch!(i16, $name).len(), utf16.len());
+        assert_eq!(env_wch!(i32, $name).len(), utf32.len());
+
+        assert_eq!(env_wchz!(u16, $name), &[&utf16[..], &[0]].concat()[..]);
+        assert_eq!(env_wchz!(u32, $name), &[&utf32[..], &[0]].concat()[..]);
+
+        assert_eq!(option_env_wch!(u16, $name), Some(&utf16[..]));
+        assert_eq!(option_env_wch!(u32, $name), Some(&utf32[..]));
+    }};
+}
+
+// Check we can use the macros to declare constants.
+const _: &[wchar_t] = env_wch!("CARGO_PKG_NAME");
+const _: &[u16] = env_wch!(u16, "CARGO_PKG_NAME");
+const _: &[u32] = env_wchz!(u32, "CARGO_PKG_NAME");
+const _: &[wchar_t] = env_wchz!("CARGO_PKG_NAME");
+const _: Option<&[wchar_t]> = option_env_wch!("CARGO_PKG_NAME");
+const _: Option<&[i16]> = option_env_wch!(i16, "CARGO_PKG_NAME");
+
+#[test]
+fn defined() {
+    test_env_wch!("CARGO_PKG_NAME");
+    test_env_wch!("CARGO_PKG_VERSION");
+    test_env_wch!("CARGO_MANIFEST_DIR");
+}
+
+#[test]
+fn trailing_comma() {
+    assert_eq!(
+        env_wch!(u16, "CARGO_PKG_NAME",),
+        env_wch!(u16, "CARGO_PKG_NAME")
+    );
+}
+
+#[test]
+fn undefined() {
+    assert_eq!(option_env_wch!(u16, "WCHAR_NOT_DEFINED"), None);
+    assert_eq!(option_env_wch!(u32, "WCHAR_NOT_DEFINED"), None);
+    assert_eq!(option_env_wch!("WCHAR_NOT_DEFINED"), None);
+}
@@ -0,0 +1,36 @@
+use wchar::{wch, wchar_t, wchz};
+
+// Check we can use the macro to declare constants.
+const _: &[wchar_t] = wch!(wchar_t, ill_formed, r"\u[D800]");
+const _: &[u16] = wch!(u16, ill_formed, r"\u[D800]");
+const _: &[u32] = wchz!(u32, ill_formed, r"\u[D800]");
+
+#[test]
+fn lone_surrogates() {
+    assert_eq!(wch!(u16, ill_formed, r"\u[D800]"), &[0xD800]);
+    assert_eq!(wch!(u16, ill_formed, r"a\u[dc00]b"), &[0x61, 0xDC00, 0x62]);
+    assert_eq!(wch!(i16, ill_formed, r"\u[DFFF]"), &[0xDFFFu16 as i16]);
+    assert_eq!(wch!(u32, ill_formed, r"\u[D800]🦀"), &[0xD800, 0x1F980]);
+    assert_eq!(wchz!(u16, ill_formed, r"\u[DBFF]x"), &[0xDBFF, 0x78, 0]);
+}
+
+#[test]
+fn code_units() {
+    // Escapes insert any code unit, not just surrogates.
+    assert_eq!(wch!(u16, ill_formed, r"\u[41]\u[0042]"), wch!(u16, "AB"));
+    assert_eq!(wch!(u16, ill_formed, r"\u[FFFF]"), &[0xFFFF]);
+    assert_eq!(wch!(u32, ill_formed, r"\u[110000]"), &[0x11_0000]);
+    assert_eq!(wch!(i32, ill_formed, r"\u[FFFFFFFF]"), &[-1]);
+
+    // A surrogate pair written as escapes is well-formed.
+    assert_eq!(wch!(u16, ill_formed, r"\u[D83E]\u[DD80]"), wch!(u16, "🦀"));
+}
+
+#[test]
+fn other_text() {
+    assert_eq!(
+        wch!(u16, ill_formed, r"C:\users\foo"),
+        wch!(u16, "C:\\users\\foo")
+    );
+    assert_eq!(wch!(u16, ill_formed, r"\u"), wch!(u16, "\\u"));
+}
@@ -52,3 +52,16 @@ tests! {
     emoji: "data/emoji.txt";
     nul_chars: "data/nul_chars.txt";
 }
+
+#[test]
+fn transforms() {
+    use util::Wide;
+
+    let string = include_str!("data/complex.txt");
+
+    let v = include_wch!(u16, upper, "data/complex.txt");
+    assert_eq!(v, &*u16::encode_str(&string.to_uppercase()));
+
+    let v = include_wch!(u32, lower, "data/complex.txt");
+    assert_eq!(v, &*u32::encode_str(&string.to_lowercase()));
+}
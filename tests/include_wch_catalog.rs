@@ -0,0 +1,45 @@
+use wchar::{include_wch_catalog, wch, wchar_t};
+
+include_wch_catalog!(u16, mod en, concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/locales/en.po"));
+include_wch_catalog!(
+    u16,
+    /// German translations.
+    pub mod de,
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/locales/de.po"),
+    reference = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/locales/en.po"),
+);
+include_wch_catalog!(u32, mod fr, concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/locales/fr.properties"));
+include_wch_catalog!(mod platform, concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/locales/en.po"));
+
+#[test]
+fn po() {
+    assert_eq!(en::OPEN_FILE, wch!(u16, "Open file"));
+    assert_eq!(de::OPEN_FILE, wch!(u16, "Datei öffnen"));
+    assert_eq!(de::SAVE_AS, wch!(u16, "Speichern unter \"...\""));
+    assert_eq!(de::_404_NOT_FOUND, wch!(u16, "404 nicht gefunden\n"));
+
+    // Fuzzy entries are skipped.
+    assert_eq!(de::QUIT, wch!(u16, "Schließen"));
+
+    let _: &[wchar_t] = platform::QUIT;
+}
+
+#[test]
+fn properties() {
+    assert_eq!(fr::OPEN_FILE, wch!(u32, "Ouvrir le fichier"));
+    assert_eq!(fr::SAVE_AS, wch!(u32, "Enregistrer sous..."));
+    assert_eq!(fr::NOT_FOUND, wch!(u32, "404 introuvable"));
+    assert_eq!(fr::QUIT, wch!(u32, "Quitter é🦀"));
+    assert_eq!(fr::KEY_WITH_SPACES, wch!(u32, "\tvaleur"));
+}
+
+#[test]
+fn lookup() {
+    assert_eq!(de::get("Open file"), Some(de::OPEN_FILE));
+    assert_eq!(de::get("Quit"), Some(&wch!(u16, "Schließen")[..]));
+    assert_eq!(de::get("Missing"), None);
+    assert_eq!(fr::get("key with spaces"), Some(fr::KEY_WITH_SPACES));
+
+    let keys: Vec<&str> = de::ENTRIES.iter().map(|&(key, _)| key).collect();
+    assert_eq!(keys, ["404 not found", "Open file", "Quit", "Save as..."]);
+}
@@ -0,0 +1,89 @@
+use wchar::{WStrDir, include_wch_dir, wch, wchar_t};
+
+// Check we can use the macro to declare statics.
+static TEMPLATES: WStrDir<u16> = include_wch_dir!(
+    u16,
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/templates")
+);
+static TEXT: WStrDir<u32> = include_wch_dir!(
+    u32,
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/templates"),
+    glob = "**/*.txt"
+);
+static MAIL: WStrDir<i16> = include_wch_dir!(
+    i16,
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/templates"),
+    glob = "mail/*",
+);
+static PLATFORM: WStrDir<wchar_t> =
+    include_wch_dir!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/templates"));
+
+#[test]
+fn paths() {
+    let paths: Vec<&str> = TEMPLATES.iter().map(|(path, _)| path).collect();
+    assert_eq!(
+        paths,
+        [
+            "README",
+            "greeting.txt",
+            "mail/html/welcome.html",
+            "mail/unicode.txt",
+            "mail/welcome.txt",
+        ]
+    );
+    assert_eq!(PLATFORM.len(), 5);
+}
+
+#[test]
+fn glob() {
+    let paths: Vec<&str> = TEXT.iter().map(|(path, _)| path).collect();
+    assert_eq!(
+        paths,
+        ["greeting.txt", "mail/unicode.txt", "mail/welcome.txt"]
+    );
+
+    let paths: Vec<&str> = MAIL.iter().map(|(path, _)| path).collect();
+    assert_eq!(paths, ["mail/unicode.txt", "mail/welcome.txt"]);
+
+    let html = include_wch_dir!(
+        u16,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/templates"),
+        glob = "*/????/*.html"
+    );
+    assert_eq!(html.len(), 1);
+
+    let none = include_wch_dir!(
+        u16,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/templates"),
+        glob = "*.md"
+    );
+    assert!(none.is_empty());
+}
+
+#[test]
+fn lookup() {
+    assert_eq!(
+        TEMPLATES.get("greeting.txt"),
+        Some(&wch!(u16, "Hello {name}!\n")[..])
+    );
+    assert_eq!(
+        TEXT.get("mail/unicode.txt"),
+        Some(&wch!(u32, "Grüße 🦀\n")[..])
+    );
+    assert_eq!(TEMPLATES.get("mail"), None);
+    assert_eq!(TEMPLATES.get("missing.txt"), None);
+    assert!(MAIL.contains("mail/welcome.txt"));
+    assert!(!MAIL.contains("greeting.txt"));
+}
+
+#[cfg(unix)]
+#[test]
+fn symlinks() {
+    // The directory contains links to `hello.txt` and to another directory.
+    let links = include_wch_dir!(
+        u16,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/links")
+    );
+    let paths: Vec<&str> = links.iter().map(|(path, _)| path).collect();
+    assert_eq!(paths, ["hello.txt"]);
+}
@@ -0,0 +1,62 @@
+use wchar::{include_wch_lines, include_wchz_lines, wch, wchar_t, wchz};
+
+// Check we can use the macro to declare statics.
+static MENU: &[&[u16]] = include_wch_lines!(
+    u16,
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/menu.txt")
+);
+static ITEMS: &[&[u32]] = include_wch_lines!(
+    u32,
+    skip_blank,
+    skip_comments = "#",
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/menu.txt")
+);
+static ITEMS_Z: &[&[i16]] = include_wchz_lines!(
+    i16,
+    skip_comments = "#",
+    skip_blank,
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/menu.txt")
+);
+static PLATFORM: &[&[wchar_t]] = include_wch_lines!(
+    wchar_t,
+    skip_blank,
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/menu.txt")
+);
+
+#[test]
+fn lines() {
+    let expected: &[&[u16]] = &[
+        wch!(u16, "# Menu definition."),
+        wch!(u16, "File"),
+        wch!(u16, "  Open"),
+        wch!(u16, ""),
+        wch!(u16, "  # Recent files are added at runtime."),
+        wch!(u16, "  Save"),
+        wch!(u16, ""),
+        wch!(u16, "Quit 🦀"),
+    ];
+    assert_eq!(MENU, expected);
+    assert_eq!(PLATFORM.len(), 6);
+}
+
+#[test]
+fn skip() {
+    let expected: &[&[u32]] = &[
+        wch!(u32, "File"),
+        wch!(u32, "  Open"),
+        wch!(u32, "  Save"),
+        wch!(u32, "Quit 🦀"),
+    ];
+    assert_eq!(ITEMS, expected);
+}
+
+#[test]
+fn nul_terminated() {
+    let expected: &[&[i16]] = &[
+        wchz!(i16, "File"),
+        wchz!(i16, "  Open"),
+        wchz!(i16, "  Save"),
+        wchz!(i16, "Quit 🦀"),
+    ];
+    assert_eq!(ITEMS_Z, expected);
+}
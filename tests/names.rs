@@ -0,0 +1,57 @@
+#![cfg(feature = "unicode-names")]
+
+use wchar::{wch, wchar_t, wchz};
+
+// Check we can use the macro to declare constants.
+const _: &[wchar_t] = wch!(wchar_t, names, r"\N{BLACK STAR}");
+const _: &[u16] = wch!(u16, names, r"\N{BLACK STAR}");
+const _: &[u32] = wchz!(u32, names, r"\N{BLACK STAR}");
+
+#[test]
+fn names() {
+    assert_eq!(
+        wch!(u16, names, r"\N{RIGHT-TO-LEFT MARK}abc"),
+        wch!(u16, "\u{200F}abc")
+    );
+    assert_eq!(
+        wchz!(
+            u32,
+            names,
+            r"a\N{EM DASH}b\N{LEFT-POINTING DOUBLE ANGLE QUOTATION MARK}"
+        ),
+        wchz!(u32, "a\u{2014}b\u{AB}")
+    );
+    assert_eq!(wch!(i16, names, r"\N{CRAB}"), wch!(i16, "🦀"));
+
+    // Names are matched case-insensitively.
+    assert_eq!(
+        wch!(i32, names, r"\N{latin small letter a}"),
+        wch!(i32, "a")
+    );
+
+    // Escapes can also be written in non-raw literals, with an escaped backslash.
+    assert_eq!(wch!(u16, names, "\\N{NO-BREAK SPACE}"), wch!(u16, "\u{A0}"));
+    assert_eq!(
+        wch!(u16, names, "\t\\N{EM DASH}\u{41}\n"),
+        wch!(u16, "\t\u{2014}A\n")
+    );
+
+    // Other backslashes are left alone.
+    assert_eq!(wch!(u16, names, r"C:\Names\n"), wch!(u16, "C:\\Names\\n"));
+
+    // An escaped backslash keeps the escape as written.
+    assert_eq!(wch!(u16, names, r"\\N{CRAB}"), wch!(u16, r"\N{CRAB}"));
+    assert_eq!(wch!(u16, names, r"\\\N{CRAB}"), wch!(u16, r"\🦀"));
+}
+
+#[test]
+fn with_transforms() {
+    assert_eq!(
+        wch!(u16, names, upper, r"\N{LATIN SMALL LETTER E WITH ACUTE}"),
+        wch!(u16, "\u{C9}")
+    );
+    assert_eq!(
+        wch!(u16, nfc, names, r"e\N{COMBINING ACUTE ACCENT}"),
+        wch!(u16, "\u{E9}")
+    );
+}
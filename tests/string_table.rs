@@ -0,0 +1,79 @@
+use wchar::{StringTable, string_table};
+
+// Check we can use the macro to declare constants.
+const _: StringTable = string_table!(1 => "const");
+
+static STRINGS: StringTable = string_table! {
+    16 => "B",
+    0 => "A",
+    15 => "é",
+    100 => "🦀",
+};
+
+#[test]
+fn blocks() {
+    assert_eq!(STRINGS.len(), 3);
+    assert_eq!(
+        STRINGS.blocks().map(|(id, _)| id).collect::<Vec<_>>(),
+        [1, 2, 7]
+    );
+    assert_eq!(STRINGS.block(3), None);
+}
+
+// The data of each block matches the output of `llvm-rc` for the
+// equivalent `STRINGTABLE` statement.
+#[test]
+fn known_good() {
+    let mut block = Vec::new();
+    block.extend_from_slice(b"\x01\0A\0");
+    block.extend_from_slice(&[0; 2 * 14]);
+    block.extend_from_slice(b"\x01\0\xE9\0");
+    assert_eq!(STRINGS.block(1), Some(&block[..]));
+
+    let mut block = Vec::new();
+    block.extend_from_slice(b"\x01\0B\0");
+    block.extend_from_slice(&[0; 2 * 15]);
+    assert_eq!(STRINGS.block(2), Some(&block[..]));
+
+    let mut block = Vec::new();
+    block.extend_from_slice(&[0; 2 * 4]);
+    block.extend_from_slice(b"\x02\0\x3E\xD8\x80\xDD");
+    block.extend_from_slice(&[0; 2 * 11]);
+    assert_eq!(STRINGS.block(7), Some(&block[..]));
+}
+
+#[test]
+fn get() {
+    assert_eq!(STRINGS.get(0), Some(&b"A\0"[..]));
+    assert_eq!(STRINGS.get(15), Some(&b"\xE9\0"[..]));
+    assert_eq!(STRINGS.get(16), Some(&b"B\0"[..]));
+    assert_eq!(STRINGS.get(100), Some(&b"\x3E\xD8\x80\xDD"[..]));
+
+    // Strings missing from a present block are empty.
+    assert_eq!(STRINGS.get(1), Some(&b""[..]));
+    assert_eq!(STRINGS.get(32), None);
+}
+
+#[test]
+fn limits() {
+    let table = string_table!(65535 => "max");
+
+    assert_eq!(table.blocks().next().map(|(id, _)| id), Some(4096));
+    assert_eq!(table.get(65535), Some(&b"m\0a\0x\0"[..]));
+}
+
+#[test]
+fn empty() {
+    let table = string_table!();
+
+    assert!(table.is_empty());
+    assert_eq!(table.get(0), None);
+}
+
+#[test]
+fn malformed() {
+    static BLOCKS: [(u16, &[u8]); 1] = [(1, b"\x05\0A\0")];
+    let table = StringTable::from_sorted_blocks(&BLOCKS);
+
+    assert_eq!(table.get(0), None);
+}
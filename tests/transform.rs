@@ -0,0 +1,91 @@
+use wchar::{wch, wchar_t, wchz};
+
+// Check we can use the macro to declare constants.
+const _: &[wchar_t] = wch!(wchar_t, upper, "const");
+const _: &[wchar_t] = wchz!(wchar_t, nfc, lower, "const");
+const _: &[u16] = wch!(u16, dedent, "const");
+const _: &[u32] = wchz!(u32, nfkd, "const");
+
+#[test]
+fn case_mapping() {
+    assert_eq!(
+        wch!(u16, upper, "CompareString"),
+        wch!(u16, "COMPARESTRING")
+    );
+    assert_eq!(
+        wch!(u32, lower, "CompareString"),
+        wch!(u32, "comparestring")
+    );
+    assert_eq!(wchz!(i16, upper, "straße"), wchz!(i16, "STRASSE"));
+    assert_eq!(wchz!(i32, lower, "ΣΊΣΥΦΟΣ"), wchz!(i32, "σίσυφος"));
+
+    assert_eq!(wch!(u16, upper, 'a'), wch!(u16, 'A'));
+    assert_eq!(wch!(u32, lower, 'Ä'), wch!(u32, 'ä'));
+}
+
+#[test]
+fn normalization() {
+    // "é" as a precomposed character and as "e" with a combining acute accent.
+    assert_eq!(wch!(u16, nfc, "e\u{301}"), wch!(u16, "\u{e9}"));
+    assert_eq!(wch!(u16, nfd, "\u{e9}"), wch!(u16, "e\u{301}"));
+    assert_eq!(wch!(u16, nfc, "\u{e9}"), wch!(u16, "\u{e9}"));
+
+    // Compatibility forms decompose the "ﬁ" ligature.
+    assert_eq!(wch!(u32, nfkc, "\u{fb01}le"), wch!(u32, "file"));
+    assert_eq!(wch!(u32, nfkd, "\u{fb01}l\u{e9}"), wch!(u32, "file\u{301}"));
+    assert_eq!(wch!(u32, nfc, "\u{fb01}le"), wch!(u32, "\u{fb01}le"));
+
+    assert_eq!(wch!(u16, nfc, 'e'), wch!(u16, 'e'));
+}
+
+#[test]
+fn dedent() {
+    let help = wch!(
+        u16,
+        dedent,
+        "
+        usage: foo [options]
+
+        options:
+            -h, --help  print help
+    "
+    );
+    assert_eq!(
+        help,
+        wch!(
+            u16,
+            "usage: foo [options]\n\noptions:\n    -h, --help  print help\n"
+        )
+    );
+
+    // Blank lines are emptied, and don't count towards the indentation.
+    assert_eq!(
+        wchz!(u32, dedent, "  foo\n      \n    bar"),
+        wchz!(u32, "foo\n\n  bar")
+    );
+    assert_eq!(wch!(u16, dedent, "\tfoo\r\n\tbar"), wch!(u16, "foo\r\nbar"));
+    assert_eq!(wch!(u16, dedent, "foo\n  bar"), wch!(u16, "foo\n  bar"));
+
+    // Tabs and spaces are different characters, only the common prefix is removed.
+    assert_eq!(
+        wch!(u16, dedent, "\tfoo\n    bar"),
+        wch!(u16, "\tfoo\n    bar")
+    );
+    assert_eq!(wch!(u16, dedent, "\t  foo\n\t bar"), wch!(u16, " foo\nbar"));
+
+    let empty: &[u16] = wch!(u16, dedent, "");
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn combined() {
+    // Transforms are applied in a fixed order, whatever order they are given in.
+    assert_eq!(
+        wch!(u16, upper, dedent, nfc, "\n  e\u{301}\n   x"),
+        wch!(u16, "\u{c9}\n X")
+    );
+    assert_eq!(
+        wch!(u16, nfc, dedent, upper, "\n  e\u{301}\n   x"),
+        wch!(u16, "\u{c9}\n X")
+    );
+}
@@ -0,0 +1,5 @@
+use wchar::wch_c;
+
+const BASIC: &[u16] = wch_c!(u16, r"\u0041");
+
+fn main() {}
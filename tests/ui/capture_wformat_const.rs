@@ -0,0 +1,6 @@
+use wchar::wformat_const;
+
+fn main() {
+    let name = "foo";
+    let _ = wformat_const!(u16, "{name}");
+}
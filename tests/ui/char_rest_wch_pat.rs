@@ -0,0 +1,5 @@
+use wchar::wch_pat;
+
+fn main() {
+    let _ = matches!(&[0u16][..], [wch_pat!(u16, 'a', ..)]);
+}
@@ -0,0 +1,5 @@
+use wchar::wch;
+
+const CONFLICTING: &[u16] = wch!(u16, upper, lower, "foo");
+
+fn main() {}
@@ -0,0 +1,18 @@
+use wchar::WideName;
+
+#[derive(WideName)]
+#[wide(u16)]
+#[wide(u32)]
+enum Shape {
+    Circle,
+}
+
+#[derive(WideName)]
+#[wide(u16)]
+enum Color {
+    #[wide(rename = "red")]
+    #[wide(rename = "crimson")]
+    Red,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use wchar::string_table;
+
+fn main() {
+    let _ = string_table! {
+        1 => "foo",
+        1 => "bar",
+    };
+}
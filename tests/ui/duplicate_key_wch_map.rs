@@ -0,0 +1,11 @@
+use wchar::{wch_map, WStrMap};
+
+static MAP: WStrMap<u16, u32> = wch_map! {
+    u16,
+    ignore_ascii_case,
+    "foo" => 1,
+    "bar" => 2,
+    "FOO" => 3,
+};
+
+fn main() {}
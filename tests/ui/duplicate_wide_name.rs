@@ -0,0 +1,11 @@
+use wchar::WideName;
+
+#[derive(WideName)]
+#[wide(u16)]
+enum Shape {
+    Circle,
+    #[wide(rename = "Circle")]
+    Round,
+}
+
+fn main() {}
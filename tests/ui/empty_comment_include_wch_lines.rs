@@ -0,0 +1,5 @@
+use wchar::include_wch_lines;
+
+static LINES: &[&[u16]] = include_wch_lines!(u16, skip_comments = "", "missing.txt");
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::include_wch_lines;
+
+fn main() {
+    let _ = include_wch_lines!(u16, concat!(env!("HOME"), "/data/menu.txt"));
+}
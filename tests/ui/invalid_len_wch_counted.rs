@@ -0,0 +1,5 @@
+use wchar::wch_counted;
+
+fn main() {
+    let _ = wch_counted!(u16, len = u64, "foo");
+}
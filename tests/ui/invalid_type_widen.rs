@@ -0,0 +1,7 @@
+#[wchar::widen(u16)]
+mod strings {
+    #[wide]
+    const NAME: String = "name";
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::wch;
+
+const MISPLACED: &[u16] = wch!(u16, upper, u32, "foo");
+
+fn main() {}
@@ -0,0 +1,10 @@
+use wchar::include_wch_catalog;
+
+include_wch_catalog!(
+    u16,
+    mod es,
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../../../../tests/data/locales/es.po"),
+    reference = concat!(env!("CARGO_MANIFEST_DIR"), "/../../../../tests/data/locales/en.po"),
+);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use wchar::WideName;
+
+#[derive(WideName)]
+enum Shape {
+    Circle,
+    Square,
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::wch;
+
+const MULTI_CHAR: u16 = wch!(u16, upper, 'ß');
+
+fn main() {}
@@ -0,0 +1,10 @@
+use wchar::WideName;
+
+#[derive(WideName)]
+#[wide(u16)]
+enum Shape {
+    Circle(f32),
+    Square,
+}
+
+fn main() {}
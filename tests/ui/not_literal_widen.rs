@@ -0,0 +1,9 @@
+#[wchar::widen(u16)]
+mod strings {
+    const NAME: &str = "name";
+
+    #[wide]
+    const ALIAS: &str = NAME;
+}
+
+fn main() {}
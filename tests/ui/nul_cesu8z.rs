@@ -0,0 +1,5 @@
+use wchar::cesu8z;
+
+fn main() {
+    let _ = cesu8z!("foo\0bar");
+}
@@ -0,0 +1,5 @@
+use wchar::cpz;
+
+const INVALID: &[u8] = cpz!(1252, "nul\0");
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::wchz;
+
+const INVALID: &[u16] = wchz!(u16, ill_formed, r"nul\u[0]");
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::wchz_c;
+
+const INVALID: &[u16] = wchz_c!(u16, r"nul\0");
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::wchz_pat;
+
+fn main() {
+    let _ = matches!(&[0u16][..], wchz_pat!(u16, "nul\0"));
+}
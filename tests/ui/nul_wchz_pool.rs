@@ -0,0 +1,11 @@
+use wchar::wchz_pool;
+
+wchz_pool! {
+    u16,
+    mod strings {
+        FOO = "foo",
+        NUL = "nul\0",
+    }
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::wformatz_const;
+
+fn main() {
+    let _ = wformatz_const!(u16, "{}", "nul\0");
+}
@@ -0,0 +1,10 @@
+use wchar::WideName;
+
+#[derive(WideName)]
+#[wide(u16, nul)]
+enum Shape {
+    #[wide(rename = "Circle\0")]
+    Circle,
+}
+
+fn main() {}
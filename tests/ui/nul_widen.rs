@@ -0,0 +1,7 @@
+#[wchar::widen(u16)]
+mod strings {
+    #[wide(nul)]
+    const NAME: &str = "name\0";
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use wchar::wch_pool;
+
+wch_pool! {
+    u16,
+    mod strings {
+        FOO = "foo",
+        POOL = "pool",
+    }
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::wch;
+
+const INVALID: &[u16] = wch!(u16, ill_formed, latin1, b"\\u[D800]");
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::wch_c;
+
+const INVALID: &[u16] = wch_c!(u16, r"\uD800");
+
+fn main() {}
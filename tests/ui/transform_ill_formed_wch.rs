@@ -0,0 +1,5 @@
+use wchar::wch;
+
+const INVALID: &[u16] = wch!(u16, ill_formed, upper, r"\u[D800]");
+
+fn main() {}
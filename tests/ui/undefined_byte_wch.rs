@@ -0,0 +1,5 @@
+use wchar::wch;
+
+const INVALID: &[u16] = wch!(u16, cp1252, b"\x81");
+
+fn main() {}
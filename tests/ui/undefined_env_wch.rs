@@ -0,0 +1,5 @@
+use wchar::env_wch;
+
+fn main() {
+    let _ = env_wch!(u16, "WCHAR_NOT_DEFINED");
+}
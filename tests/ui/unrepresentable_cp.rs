@@ -0,0 +1,5 @@
+use wchar::cp;
+
+const INVALID: &[u8] = cp!(437, "€");
+
+fn main() {}
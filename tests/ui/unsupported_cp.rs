@@ -0,0 +1,5 @@
+use wchar::cp;
+
+const INVALID: &[u8] = cp!(1250, "foo");
+
+fn main() {}
@@ -0,0 +1,9 @@
+use wchar::include_wch_catalog;
+
+include_wch_catalog!(
+    u16,
+    mod basic,
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../../../../tests/data/basic.txt"),
+);
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::wformat_const;
+
+fn main() {
+    let _ = wformat_const!(u16, "{}", "foo", "bar");
+}
@@ -0,0 +1,5 @@
+use wchar::wchz;
+
+const INVALID: &[u16] = wchz!(u16, latin1, "text");
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::wch;
+
+const OVERLONG: &[u16] = wch!(u16, names, r"\N{AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA}");
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::wch;
+
+const UNKNOWN: &[u16] = wch!(u16, names, "\t\u{2014} \\N{NOT A CHARACTER NAME}\n");
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::wch;
+
+const UNKNOWN: &[u16] = wch!(u16, names, r"\N{NOT A CHARACTER NAME}");
+
+fn main() {}
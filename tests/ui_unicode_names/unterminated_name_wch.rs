@@ -0,0 +1,5 @@
+use wchar::wch;
+
+const UNTERMINATED: &[u16] = wch!(u16, names, r"\N{RIGHT-TO-LEFT MARK");
+
+fn main() {}
@@ -0,0 +1,5 @@
+use wchar::include_wchz_lines;
+
+static LINES: &[&[u16]] = include_wchz_lines!(u16, "../data/nul_lines.txt");
+
+fn main() {}
@@ -0,0 +1,83 @@
+use core::mem;
+
+use wchar::{UnicodeString, unicode_string, wch};
+
+// Check we can use the macro to declare constants and statics.
+const NAME: UnicodeString = unicode_string!("Name");
+static STATIC: UnicodeString = unicode_string!("static");
+
+#[test]
+fn layout() {
+    assert_eq!(mem::size_of::<UnicodeString>(), 2 * mem::size_of::<usize>());
+    assert_eq!(mem::align_of::<UnicodeString>(), mem::align_of::<usize>());
+
+    #[repr(C)]
+    struct Fields {
+        length: u16,
+        maximum_length: u16,
+        buffer: *const u16,
+    }
+
+    let fields: Fields = unsafe { mem::transmute(NAME) };
+    assert_eq!(fields.length, 8);
+    assert_eq!(fields.maximum_length, 8);
+    assert_eq!(fields.buffer, NAME.as_slice().as_ptr());
+}
+
+#[test]
+fn lengths() {
+    assert_eq!(NAME.length(), 8);
+    assert_eq!(NAME.maximum_length(), 8);
+    assert_eq!(NAME.len(), 4);
+    assert_eq!(NAME.as_slice(), wch!(u16, "Name"));
+    assert_eq!(NAME.as_ptr(), NAME.as_slice().as_ptr());
+
+    assert_eq!(STATIC.as_slice(), wch!(u16, "static"));
+
+    let crab = unicode_string!("🦀");
+    assert_eq!(crab.length(), 4);
+    assert_eq!(crab.as_slice(), wch!(u16, "🦀"));
+}
+
+#[test]
+fn empty() {
+    let empty = unicode_string!("");
+
+    assert!(empty.is_empty());
+    assert_eq!(empty.length(), 0);
+    assert_eq!(empty.maximum_length(), 0);
+    assert!(empty.as_slice().is_empty());
+}
+
+#[test]
+fn transforms() {
+    assert_eq!(
+        unicode_string!(lower, "\\DEVICE").as_slice(),
+        wch!(u16, "\\device")
+    );
+}
+
+#[test]
+fn from_units() {
+    static UNITS: [u16; (u16::MAX / 2) as usize] = [0x0061; (u16::MAX / 2) as usize];
+    let longest = UnicodeString::from_units(&UNITS);
+
+    assert_eq!(longest.length(), u16::MAX - 1);
+    assert_eq!(longest.as_slice(), &UNITS[..]);
+}
+
+#[test]
+#[should_panic = "`UNICODE_STRING` length must not exceed `u16::MAX` bytes"]
+fn from_units_overflow() {
+    static UNITS: [u16; 0x8000] = [0; 0x8000];
+
+    let _ = UnicodeString::from_units(&UNITS);
+}
+
+#[test]
+fn debug() {
+    assert_eq!(
+        format!("{:?}", unicode_string!("ab")),
+        "UnicodeString([97, 98])"
+    );
+}
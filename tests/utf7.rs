@@ -0,0 +1,123 @@
+use wchar::{
+    Utf7Error, decode_imap_utf7, decode_utf7, encode_imap_utf7, encode_utf7, imap_utf7, utf7, wch,
+};
+
+// Check we can use the macro to declare constants.
+const _: &[u8] = utf7!("const");
+const _: &[u8] = imap_utf7!("const");
+
+fn encode(units: &[u16], imap: bool) -> Vec<u8> {
+    let units = units.iter().copied();
+    if imap {
+        encode_imap_utf7(units).collect()
+    } else {
+        encode_utf7(units).collect()
+    }
+}
+
+fn error(result: Result<Vec<u16>, Utf7Error>) -> usize {
+    result.unwrap_err().index()
+}
+
+#[test]
+fn utf7() {
+    assert_eq!(utf7!("A≢Α."), b"A+ImIDkQ.");
+    assert_eq!(utf7!("日本語"), b"+ZeVnLIqe-");
+    assert_eq!(utf7!("£1"), b"+AKM-1");
+    assert_eq!(utf7!("£-"), b"+AKM--");
+    assert_eq!(utf7!("£ "), b"+AKM ");
+    assert_eq!(utf7!("1 + 1"), b"1 +- 1");
+    assert_eq!(utf7!("a~b!"), b"a+AH4-b+ACE-");
+    assert_eq!(utf7!("🦀"), b"+2D7dgA-");
+
+    let empty: &[u8] = utf7!("");
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn imap_utf7() {
+    assert_eq!(
+        imap_utf7!("~peter/mail/台北/日本語"),
+        b"~peter/mail/&U,BTFw-/&ZeVnLIqe-"
+    );
+    assert_eq!(imap_utf7!("Q&A"), b"Q&-A");
+    assert_eq!(imap_utf7!("Entwürfe"), b"Entw&APw-rfe");
+    assert_eq!(imap_utf7!("ü&ü"), b"&APw-&-&APw-");
+    assert_eq!(imap_utf7!("\t"), b"&AAk-");
+}
+
+#[test]
+fn encode_runtime() {
+    assert_eq!(encode(wch!(u16, "A≢Α."), false), utf7!("A≢Α."));
+    assert_eq!(encode(wch!(u16, "1 + 1 ≠ 3"), false), utf7!("1 + 1 ≠ 3"));
+    assert_eq!(encode(wch!(u16, "£-🦀~"), false), utf7!("£-🦀~"));
+
+    assert_eq!(
+        encode(wch!(u16, "~peter/mail/台北"), true),
+        imap_utf7!("~peter/mail/台北")
+    );
+    assert_eq!(encode(wch!(u16, "Q&A ü&ü"), true), imap_utf7!("Q&A ü&ü"));
+    assert_eq!(encode(wch!(u16, "🦀"), true), imap_utf7!("🦀"));
+
+    // Lone surrogates are encoded as is.
+    assert_eq!(encode(&[0xD800], false), b"+2AA-");
+}
+
+#[test]
+fn decode_round_trip() {
+    let text = wch!(u16, "Hi Mom -☺-! 1 + 1 ≠ 3 ~/🦀");
+
+    let units: Result<Vec<u16>, _> = decode_utf7(utf7!("Hi Mom -☺-! 1 + 1 ≠ 3 ~/🦀")).collect();
+    assert_eq!(units.unwrap(), text);
+
+    let units: Result<Vec<u16>, _> =
+        decode_imap_utf7(imap_utf7!("Hi Mom -☺-! 1 + 1 ≠ 3 ~/🦀")).collect();
+    assert_eq!(units.unwrap(), text);
+
+    // Optional direct characters and unterminated runs are accepted in UTF-7.
+    let units: Result<Vec<u16>, _> = decode_utf7(b"Hi Mom -+Jjo--!").collect();
+    assert_eq!(units.unwrap(), wch!(u16, "Hi Mom -☺-!"));
+    let units: Result<Vec<u16>, _> = decode_utf7(b"+AKM").collect();
+    assert_eq!(units.unwrap(), wch!(u16, "£"));
+
+    assert!(decode_utf7(b"").next().is_none());
+}
+
+#[test]
+fn decode_utf7_errors() {
+    // Non-zero padding bits.
+    assert_eq!(error(decode_utf7(b"+AOl-").collect()), 4);
+    // Leftover bits that make up a whole base64 character.
+    assert_eq!(error(decode_utf7(b"a+AOkA-").collect()), 6);
+    // Empty runs.
+    assert_eq!(error(decode_utf7(b"a+.").collect()), 1);
+    assert_eq!(error(decode_utf7(b"+").collect()), 0);
+    // Characters that must be encoded.
+    assert_eq!(error(decode_utf7(b"a\\").collect()), 1);
+    assert_eq!(error(decode_utf7("é".as_bytes()).collect()), 0);
+    // The IMAP alphabet.
+    assert_eq!(error(decode_utf7(b"+U,BTFw-").collect()), 2);
+
+    let err = decode_utf7(b"ab~").nth(2).unwrap().unwrap_err();
+    assert_eq!(err.to_string(), "invalid UTF-7 at index 2");
+
+    // Decoding stops after the first error.
+    let mut iter = decode_utf7(b"~a");
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn decode_imap_utf7_errors() {
+    // Unterminated runs.
+    assert_eq!(error(decode_imap_utf7(b"&APw").collect()), 4);
+    assert_eq!(error(decode_imap_utf7(b"&APw.").collect()), 4);
+    // Printable ASCII encoded in base64.
+    assert_eq!(error(decode_imap_utf7(b"&AGE-").collect()), 4);
+    // Adjacent runs.
+    assert_eq!(error(decode_imap_utf7(b"&APw-&APw-").collect()), 5);
+    // Characters that must be encoded.
+    assert_eq!(error(decode_imap_utf7(b"a\tb").collect()), 1);
+    // The UTF-7 alphabet.
+    assert_eq!(error(decode_imap_utf7(b"&U/BTFw-").collect()), 2);
+}
@@ -1,3 +1,6 @@
+// Not every test uses every helper.
+#![allow(dead_code)]
+
 use std::char;
 use std::convert::TryFrom;
 use std::iter::once;
@@ -0,0 +1,62 @@
+use wchar::{wch, wch_c, wchar_t, wchz, wchz_c};
+
+// Check we can use the macro to declare constants.
+const _: &[wchar_t] = wch_c!(r"const\n");
+const _: &[u16] = wch_c!(u16, r"const\n");
+const _: &[u32] = wch_c!(u32, r"const\n");
+const _: &[i16] = wchz_c!(i16, r"const\n");
+const _: &[i32] = wchz_c!(i32, r"const\n");
+
+#[test]
+fn simple_escapes() {
+    assert_eq!(
+        wch_c!(u16, r#"\'\"\?\\\a\b\e\f\n\r\t\v"#),
+        wch!(u16, "'\"?\\\x07\x08\x1B\x0C\n\r\t\x0B")
+    );
+    assert_eq!(
+        wch_c!(u32, r"no escapes, 京 🦀"),
+        wch!(u32, "no escapes, 京 🦀")
+    );
+}
+
+#[test]
+fn octal_escapes() {
+    assert_eq!(wch_c!(u16, r"\101\0102\7"), wch!(u16, "A\x082\x07"));
+    assert_eq!(wch_c!(u32, r"\0\00\000"), &[0, 0, 0]);
+    assert_eq!(wch_c!(u16, r"\777"), &[0o777]);
+    // Only three digits are consumed.
+    assert_eq!(wch_c!(u32, r"\1010"), wch!(u32, "A0"));
+}
+
+#[test]
+fn hex_escapes() {
+    assert_eq!(wch_c!(u16, r"\x41\x042"), wch!(u16, "AB"));
+    // Hex escapes consume every following hex digit, as in C.
+    assert_eq!(wch_c!(u16, r"\x41BC"), &[0x41BC]);
+    assert_eq!(wch_c!(u16, r"\x0000000041"), &[0x41]);
+    assert_eq!(wch_c!(u16, r"\xFFFF"), &[0xFFFF]);
+    assert_eq!(wch_c!(i16, r"\xFFFF"), &[-1]);
+    assert_eq!(wch_c!(u32, r"\xFFFFFFFF"), &[0xFFFF_FFFF]);
+    assert_eq!(wch_c!(i32, r"\xFFFFFFFF"), &[-1]);
+
+    // Lone surrogates are emitted as is.
+    assert_eq!(wch_c!(u16, r"a\xD800z"), &[0x61, 0xD800, 0x7A]);
+    assert_eq!(wch_c!(u32, r"\xDFFF"), &[0xDFFF]);
+}
+
+#[test]
+fn universal_character_names() {
+    assert_eq!(wch_c!(u16, r"é\U0001F600"), wch!(u16, "é😀"));
+    assert_eq!(wch_c!(u32, r"é\U0001F600"), wch!(u32, "é😀"));
+    assert_eq!(wch_c!(i16, r"\U0001F600"), wch!(i16, "😀"));
+    // Only the given number of digits are consumed.
+    assert_eq!(wch_c!(u16, r"\u00E91"), wch!(u16, "é1"));
+    // Characters below U+00A0 can only be named if not in the basic character set.
+    assert_eq!(wch_c!(u16, r"\u0024\u0040\U00000060"), wch!(u16, "$@`"));
+}
+
+#[test]
+fn nul_terminated() {
+    assert_eq!(wchz_c!(u16, r"foo\tbar"), wchz!(u16, "foo\tbar"));
+    assert_eq!(wchz_c!(u32, r"\xD800"), &[0xD800, 0]);
+}
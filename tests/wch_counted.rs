@@ -0,0 +1,109 @@
+use wchar::{split_counted, wch, wch_counted, wchar_t};
+
+// Check we can use the macro to declare constants.
+const _: &[u16] = wch_counted!(u16, len = u16, "const");
+const _: &[wchar_t] = wch_counted!(len = u8, "const");
+
+#[test]
+fn prefix() {
+    assert_eq!(
+        wch_counted!(u16, len = u8, "foo"),
+        &[3, 0x0066, 0x006F, 0x006F]
+    );
+    assert_eq!(
+        wch_counted!(u16, len = u16, "foo"),
+        &[3, 0x0066, 0x006F, 0x006F]
+    );
+    assert_eq!(
+        wch_counted!(u32, len = u32, "foo"),
+        &[3, 0x0066, 0x006F, 0x006F]
+    );
+    assert_eq!(wch_counted!(i16, len = u16, "a"), &[1, 0x0061]);
+    assert_eq!(wch_counted!(i32, len = u8, "a"), &[1, 0x0061]);
+
+    // The count is in code units.
+    assert_eq!(wch_counted!(u16, len = u8, "🦀"), &[2, 0xD83E, 0xDD80]);
+    assert_eq!(wch_counted!(u32, len = u8, "🦀"), &[1, 0x1F980]);
+
+    assert_eq!(wch_counted!(u16, len = u16, ""), &[0]);
+}
+
+#[test]
+fn wide_prefix() {
+    let counted = wch_counted!(u16, len = u32, "ab");
+
+    if cfg!(target_endian = "little") {
+        assert_eq!(counted, &[2, 0, 0x0061, 0x0062]);
+    } else {
+        assert_eq!(counted, &[0, 2, 0x0061, 0x0062]);
+    }
+
+    // The prefix has the memory layout of a native `u32`.
+    let prefix = u32::from_ne_bytes([
+        counted[0].to_ne_bytes()[0],
+        counted[0].to_ne_bytes()[1],
+        counted[1].to_ne_bytes()[0],
+        counted[1].to_ne_bytes()[1],
+    ]);
+    assert_eq!(prefix, 2);
+}
+
+#[test]
+fn limits() {
+    let counted = wch_counted!(
+        u16,
+        len = u8,
+        "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+    );
+    assert_eq!(counted[0], 255);
+    assert_eq!(counted.len(), 256);
+
+    let counted = wch_counted!(
+        u16,
+        len = u16,
+        "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+    );
+    assert_eq!(counted[0], 256);
+}
+
+#[test]
+fn split() {
+    let foo: &[u16] = wch_counted!(u16, len = u16, "foo");
+    let empty: &[u16] = wch_counted!(u16, len = u16, "");
+    let bar: &[u16] = wch_counted!(u16, len = u16, "bar");
+    let buf = [foo, empty, bar].concat();
+
+    let (s, rest) = split_counted::<u16, u16>(&buf).unwrap();
+    assert_eq!(s, wch!(u16, "foo"));
+    let (s, rest) = split_counted::<u16, u16>(rest).unwrap();
+    assert!(s.is_empty());
+    let (s, rest) = split_counted::<u16, u16>(rest).unwrap();
+    assert_eq!(s, wch!(u16, "bar"));
+    assert!(rest.is_empty());
+
+    assert_eq!(split_counted::<u16, u16>(rest), None);
+}
+
+#[test]
+fn split_wide_prefix() {
+    let (s, rest) = split_counted::<u32, u16>(wch_counted!(u16, len = u32, "🦀")).unwrap();
+    assert_eq!(s, wch!(u16, "🦀"));
+    assert!(rest.is_empty());
+
+    let (s, _) = split_counted::<u32, i32>(wch_counted!(i32, len = u32, "ab")).unwrap();
+    assert_eq!(s, wch!(i32, "ab"));
+
+    let (s, _) = split_counted::<u8, i16>(wch_counted!(i16, len = u8, "ab")).unwrap();
+    assert_eq!(s, wch!(i16, "ab"));
+}
+
+#[test]
+fn split_invalid() {
+    // The count exceeds the buffer.
+    assert_eq!(split_counted::<u16, u16>(&[4, 0x0061]), None);
+    // The buffer is too short for the prefix.
+    assert_eq!(split_counted::<u32, u16>(&[0]), None);
+    // The prefix unit holds a value too large for the prefix type.
+    assert_eq!(split_counted::<u8, u16>(&[0x0100, 0x0061]), None);
+    assert_eq!(split_counted::<u16, i32>(&[-1, 0x0061]), None);
+}
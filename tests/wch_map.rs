@@ -0,0 +1,126 @@
+use wchar::{WStrMap, wch, wch_map, wchar_t};
+
+#[derive(Debug, PartialEq)]
+enum Switch {
+    Help,
+    Verbose,
+    Quiet,
+}
+
+static SWITCHES: WStrMap<u16, Switch> = wch_map! {
+    u16,
+    "verbose" => Switch::Verbose,
+    "help" => Switch::Help,
+    "quiet" => Switch::Quiet,
+    "?" => Switch::Help,
+};
+
+static SWITCHES_NO_CASE: WStrMap<u16, Switch> = wch_map! {
+    u16,
+    ignore_ascii_case,
+    "Verbose" => Switch::Verbose,
+    "help" => Switch::Help,
+    "QUIET" => Switch::Quiet,
+};
+
+static VALUES: WStrMap<i32, u32> = wch_map! {
+    i32,
+    "京" => 1,
+    "🦀" => 2,
+    "crab" => 3,
+};
+
+static SURROGATES: WStrMap<i16, u32> = wch_map! {
+    i16,
+    "\u{FFFF}" => 1,
+    "🦀" => 2,
+    "a" => 3,
+};
+
+const NATIVE: WStrMap<wchar_t, &str> = wch_map! {
+    "foo" => "foo",
+    "bar" => "bar",
+};
+
+const NATIVE_NO_CASE: WStrMap<wchar_t, &str> = wch_map! {
+    wchar_t,
+    ignore_ascii_case,
+    "foo" => "foo",
+    "bar" => "bar",
+};
+
+const EMPTY: WStrMap<u32, ()> = wch_map! { u32, };
+
+#[test]
+fn get() {
+    assert_eq!(SWITCHES.get(wch!(u16, "help")), Some(&Switch::Help));
+    assert_eq!(SWITCHES.get(wch!(u16, "?")), Some(&Switch::Help));
+    assert_eq!(SWITCHES.get(wch!(u16, "verbose")), Some(&Switch::Verbose));
+    assert_eq!(SWITCHES.get(wch!(u16, "quiet")), Some(&Switch::Quiet));
+
+    assert_eq!(SWITCHES.get(wch!(u16, "HELP")), None);
+    assert_eq!(SWITCHES.get(wch!(u16, "hel")), None);
+    assert_eq!(SWITCHES.get(wch!(u16, "helper")), None);
+    assert_eq!(SWITCHES.get(&[]), None);
+
+    assert_eq!(VALUES.get(wch!(i32, "京")), Some(&1));
+    assert_eq!(VALUES.get(wch!(i32, "🦀")), Some(&2));
+    assert_eq!(VALUES.get(wch!(i32, "crab")), Some(&3));
+
+    assert_eq!(SURROGATES.get(wch!(i16, "\u{FFFF}")), Some(&1));
+    assert_eq!(SURROGATES.get(wch!(i16, "🦀")), Some(&2));
+    assert_eq!(SURROGATES.get(wch!(i16, "a")), Some(&3));
+
+    assert_eq!(NATIVE.get(wch!("foo")), Some(&"foo"));
+    assert_eq!(NATIVE.get(wch!("bar")), Some(&"bar"));
+    assert_eq!(NATIVE.get(wch!("baz")), None);
+
+    assert_eq!(EMPTY.get(wch!(u32, "")), None);
+}
+
+#[test]
+fn ignore_ascii_case() {
+    assert!(SWITCHES_NO_CASE.ignores_ascii_case());
+
+    assert_eq!(SWITCHES_NO_CASE.get(wch!(u16, "help")), Some(&Switch::Help));
+    assert_eq!(SWITCHES_NO_CASE.get(wch!(u16, "HeLp")), Some(&Switch::Help));
+    assert_eq!(
+        SWITCHES_NO_CASE.get(wch!(u16, "verbose")),
+        Some(&Switch::Verbose)
+    );
+    assert_eq!(
+        SWITCHES_NO_CASE.get(wch!(u16, "VERBOSE")),
+        Some(&Switch::Verbose)
+    );
+    assert_eq!(
+        SWITCHES_NO_CASE.get(wch!(u16, "quiet")),
+        Some(&Switch::Quiet)
+    );
+    assert_eq!(SWITCHES_NO_CASE.get(wch!(u16, "hlp")), None);
+
+    assert_eq!(
+        SWITCHES_NO_CASE.get_key_value(wch!(u16, "VERBOSE")),
+        Some((&wch!(u16, "Verbose")[..], &Switch::Verbose))
+    );
+
+    assert_eq!(NATIVE_NO_CASE.get(wch!("FOO")), Some(&"foo"));
+}
+
+#[test]
+fn iter() {
+    assert_eq!(SWITCHES.len(), 4);
+    assert!(!SWITCHES.is_empty());
+    assert!(SWITCHES.contains_key(wch!(u16, "quiet")));
+
+    let keys: Vec<&[u16]> = SWITCHES.iter().map(|(k, _)| k).collect();
+    let expected: [&[u16]; 4] = [
+        wch!(u16, "?"),
+        wch!(u16, "help"),
+        wch!(u16, "quiet"),
+        wch!(u16, "verbose"),
+    ];
+    assert_eq!(keys, expected);
+
+    assert_eq!(EMPTY.len(), 0);
+    assert!(EMPTY.is_empty());
+}
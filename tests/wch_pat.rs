@@ -0,0 +1,101 @@
+use wchar::{wch, wch_pat, wchar_t, wchz, wchz_pat};
+
+macro_rules! test_wch_pat {
+    ($s:literal) => {{
+        assert!(matches!(slice::<u16>(wch!(u16, $s)), wch_pat!(u16, $s)));
+        assert!(matches!(slice::<u32>(wch!(u32, $s)), wch_pat!(u32, $s)));
+        assert!(matches!(slice::<i16>(wch!(i16, $s)), wch_pat!(i16, $s)));
+        assert!(matches!(slice::<i32>(wch!(i32, $s)), wch_pat!(i32, $s)));
+
+        assert!(matches!(slice::<u16>(wchz!(u16, $s)), wchz_pat!(u16, $s)));
+        assert!(matches!(slice::<u32>(wchz!(u32, $s)), wchz_pat!(u32, $s)));
+        assert!(matches!(slice::<i16>(wchz!(i16, $s)), wchz_pat!(i16, $s)));
+        assert!(matches!(slice::<i32>(wchz!(i32, $s)), wchz_pat!(i32, $s)));
+    }};
+}
+
+// Match against slices, rather than the arrays the macros generate.
+fn slice<T>(s: &[T]) -> &[T] {
+    s
+}
+
+fn command(units: &[wchar_t]) -> Option<&'static str> {
+    match units {
+        wch_pat!("open") => Some("open"),
+        wch_pat!("close") => Some("close"),
+        wch_pat!("set ", ..) => Some("set"),
+        wchz_pat!("quit") => Some("quit"),
+        wchz_pat!("echo", ..) => Some("echo"),
+        [wch_pat!('#'), ..] => Some("comment"),
+        _ => None,
+    }
+}
+
+#[test]
+fn basic() {
+    test_wch_pat!("foo");
+    test_wch_pat!("bar");
+    test_wch_pat!("foo bar");
+}
+
+#[test]
+fn complex() {
+    test_wch_pat!("京");
+    test_wch_pat!("𐐷");
+    test_wch_pat!("🦀");
+    test_wch_pat!("🇬🇧");
+}
+
+#[test]
+fn empty() {
+    test_wch_pat!("");
+
+    assert!(matches!(slice::<u16>(&[]), wch_pat!(u16, "")));
+    assert!(matches!(
+        slice::<u16>(wch!(u16, "foo")),
+        wch_pat!(u16, "", ..)
+    ));
+}
+
+#[test]
+fn rest() {
+    assert!(matches!(slice(wch!(u16, "foo")), wch_pat!(u16, "foo", ..)));
+    assert!(matches!(
+        slice(wch!(u16, "foobar")),
+        wch_pat!(u16, "foo", ..)
+    ));
+    assert!(!matches!(slice(wch!(u16, "fo")), wch_pat!(u16, "foo", ..)));
+    assert!(!matches!(slice(wch!(u16, "foobar")), wch_pat!(u16, "foo")));
+
+    assert!(matches!(slice(wch!(i16, "🦀🦀")), wch_pat!(i16, "🦀", ..)));
+
+    assert!(matches!(
+        slice(wch!(u32, "foo\0bar")),
+        wchz_pat!(u32, "foo", ..)
+    ));
+    assert!(!matches!(
+        slice(wch!(u32, "foobar")),
+        wchz_pat!(u32, "foo", ..)
+    ));
+}
+
+#[test]
+fn chars() {
+    assert!(matches!(wch!(u16, 'a'), wch_pat!(u16, 'a')));
+    assert!(matches!(wch!(u32, '🦀'), wch_pat!(u32, '🦀')));
+    assert!(matches!(wch!(i16, '京'), wch_pat!(i16, '京')));
+}
+
+#[test]
+fn dispatch() {
+    assert_eq!(command(wch!("open")), Some("open"));
+    assert_eq!(command(wch!("close")), Some("close"));
+    assert_eq!(command(wch!("set foo")), Some("set"));
+    assert_eq!(command(wchz!("quit")), Some("quit"));
+    assert_eq!(command(wchz!("echo")), Some("echo"));
+    assert_eq!(command(wch!("echo\0foo")), Some("echo"));
+    assert_eq!(command(wch!("# comment")), Some("comment"));
+
+    assert_eq!(command(wch!("quit")), None);
+    assert_eq!(command(wch!("opened")), None);
+}
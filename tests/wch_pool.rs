@@ -0,0 +1,141 @@
+use wchar::{wch, wch_pool, wchar_t, wchz, wchz_pool};
+
+wch_pool! {
+    u16,
+    /// Strings encoded as UTF-16.
+    mod utf16 {
+        /// An empty string.
+        EMPTY = "",
+        FOO = "foo",
+        BAR = "bar",
+        CRAB = "🦀",
+    }
+}
+
+wch_pool! {
+    u32,
+    mod utf32 {
+        FOO = "foo",
+        CRAB = "🦀",
+    }
+}
+
+wchz_pool! {
+    i16,
+    pub(crate) mod utf16_c {
+        FOO = "foo",
+        BAR = "bar",
+    }
+}
+
+wchz_pool! {
+    i32,
+    mod utf32_c {
+        FOO = "foo",
+        BAR = "bar",
+    }
+}
+
+wch_pool! {
+    mod native {
+        FOO = "foo",
+    }
+}
+
+wchz_pool! {
+    mod native_c {
+        FOO = "foo",
+    }
+}
+
+// Check the pool can be used without a trailing comma.
+wch_pool! {
+    u16,
+    mod no_trailing_comma {
+        FOO = "foo"
+    }
+}
+
+// Check an empty pool can be declared.
+wch_pool! {
+    u16,
+    mod empty {}
+}
+
+#[test]
+fn ids() {
+    assert_eq!(utf16::EMPTY, 0);
+    assert_eq!(utf16::FOO, 1);
+    assert_eq!(utf16::BAR, 2);
+    assert_eq!(utf16::CRAB, 3);
+}
+
+#[test]
+fn lookup() {
+    assert_eq!(utf16::POOL.get(utf16::EMPTY).unwrap(), wch!(u16, ""));
+    assert_eq!(utf16::POOL.get(utf16::FOO).unwrap(), wch!(u16, "foo"));
+    assert_eq!(utf16::POOL.get(utf16::BAR).unwrap(), wch!(u16, "bar"));
+    assert_eq!(utf16::POOL.get(utf16::CRAB).unwrap(), wch!(u16, "🦀"));
+    assert_eq!(utf16::POOL.get(4), None);
+
+    assert_eq!(utf32::POOL.get(utf32::FOO).unwrap(), wch!(u32, "foo"));
+    assert_eq!(utf32::POOL.get(utf32::CRAB).unwrap(), wch!(u32, "🦀"));
+
+    assert_eq!(utf16_c::POOL.get(utf16_c::FOO).unwrap(), wchz!(i16, "foo"));
+    assert_eq!(utf16_c::POOL.get(utf16_c::BAR).unwrap(), wchz!(i16, "bar"));
+
+    assert_eq!(utf32_c::POOL.get(utf32_c::FOO).unwrap(), wchz!(i32, "foo"));
+    assert_eq!(utf32_c::POOL.get(utf32_c::BAR).unwrap(), wchz!(i32, "bar"));
+
+    let foo: &[wchar_t] = native::POOL.get(native::FOO).unwrap();
+    assert_eq!(foo, wch!("foo"));
+    let foo: &[wchar_t] = native_c::POOL.get(native_c::FOO).unwrap();
+    assert_eq!(foo, wchz!("foo"));
+
+    assert_eq!(
+        no_trailing_comma::POOL.get(no_trailing_comma::FOO).unwrap(),
+        wch!(u16, "foo")
+    );
+}
+
+#[test]
+fn ranges() {
+    const CRAB: (usize, usize) = utf16::ranges::CRAB;
+    assert_eq!(utf16::ranges::EMPTY, (0, 0));
+    assert_eq!(utf16::ranges::FOO, (0, 3));
+    assert_eq!(utf16::ranges::BAR, (3, 3));
+    assert_eq!(CRAB, (6, 2));
+    assert_eq!(&utf16::BUFFER[CRAB.0..CRAB.0 + CRAB.1], wch!(u16, "🦀"));
+
+    assert_eq!(utf32_c::ranges::FOO, (0, 4));
+    assert_eq!(utf32_c::ranges::BAR, (4, 4));
+    let (offset, len) = utf32_c::ranges::BAR;
+    assert_eq!(&utf32_c::BUFFER[offset..offset + len], wchz!(i32, "bar"));
+}
+
+#[test]
+fn buffer() {
+    assert_eq!(&utf16::BUFFER[..], wch!(u16, "foobar🦀"));
+    assert_eq!(utf16::POOL.buffer(), wch!(u16, "foobar🦀"));
+    assert_eq!(&utf32_c::BUFFER[..], wch!(i32, "foo\0bar\0"));
+}
+
+#[test]
+fn iter() {
+    assert_eq!(utf16::POOL.len(), 4);
+    assert!(!utf16::POOL.is_empty());
+
+    let strings: Vec<&[u16]> = utf16::POOL.iter().collect();
+    let expected: [&[u16]; 4] = [
+        wch!(u16, ""),
+        wch!(u16, "foo"),
+        wch!(u16, "bar"),
+        wch!(u16, "🦀"),
+    ];
+    assert_eq!(strings, expected);
+
+    assert_eq!(empty::POOL.len(), 0);
+    assert!(empty::POOL.is_empty());
+    assert_eq!(empty::POOL.iter().count(), 0);
+    assert_eq!(empty::POOL.get(0), None);
+}
@@ -0,0 +1,134 @@
+use std::fmt::Debug;
+
+use wchar::{wch, wchz};
+use wchar_build::{WCharType, WideStrings};
+
+fn constant<T: Debug>(name: &str, ty: &str, units: &[T]) -> String {
+    format!("pub const {}: &[{}] = &{:?};", name, ty, units)
+}
+
+macro_rules! test_wchar_build {
+    ($text:literal) => {{
+        let source = WideStrings::new()
+            .add("WCH_U16", $text, WCharType::U16, false)
+            .add("WCH_U32", $text, WCharType::U32, false)
+            .add("WCH_I16", $text, WCharType::I16, false)
+            .add("WCH_I32", $text, WCharType::I32, false)
+            .add("WCHZ_U16", $text, WCharType::U16, true)
+            .add("WCHZ_U32", $text, WCharType::U32, true)
+            .add("WCHZ_I16", $text, WCharType::I16, true)
+            .add("WCHZ_I32", $text, WCharType::I32, true)
+            .generate()
+            .unwrap();
+
+        let lines: Vec<&str> = source
+            .lines()
+            .filter(|line| line.starts_with("pub"))
+            .collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                constant::<u16>("WCH_U16", "u16", wch!(u16, $text)),
+                constant::<u32>("WCH_U32", "u32", wch!(u32, $text)),
+                constant::<i16>("WCH_I16", "i16", wch!(i16, $text)),
+                constant::<i32>("WCH_I32", "i32", wch!(i32, $text)),
+                constant::<u16>("WCHZ_U16", "u16", wchz!(u16, $text)),
+                constant::<u32>("WCHZ_U32", "u32", wchz!(u32, $text)),
+                constant::<i16>("WCHZ_I16", "i16", wchz!(i16, $text)),
+                constant::<i32>("WCHZ_I32", "i32", wchz!(i32, $text)),
+            ]
+        );
+    }};
+}
+
+#[test]
+fn matches_macros() {
+    test_wchar_build!("");
+    test_wchar_build!("foo");
+    test_wchar_build!("foo\tbar\n");
+    test_wchar_build!("héllo wörld");
+    test_wchar_build!("🦀 Rust 🦀");
+    test_wchar_build!("\u{FFFF}\u{10000}\u{10FFFF}");
+}
+
+#[test]
+fn source() {
+    let source = WideStrings::new()
+        .add("FOO", "foo", WCharType::U16, false)
+        .add("_bar", "bar", WCharType::I32, true)
+        .generate()
+        .unwrap();
+
+    assert_eq!(
+        source,
+        "// Generated by wchar-build, do not edit.\n\
+         \n\
+         pub const FOO: &[u16] = &[102, 111, 111];\n\
+         \n\
+         pub const _bar: &[i32] = &[98, 97, 114, 0];\n"
+    );
+}
+
+#[test]
+fn errors() {
+    let error = |name: &str, text: &str, nul_terminated: bool| {
+        WideStrings::new()
+            .add(name, text, WCharType::U16, nul_terminated)
+            .generate()
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert_eq!(error("", "foo", false), "`` is not a valid constant name");
+    assert_eq!(
+        error("1FOO", "foo", false),
+        "`1FOO` is not a valid constant name"
+    );
+    assert_eq!(
+        error("FOO BAR", "foo", false),
+        "`FOO BAR` is not a valid constant name"
+    );
+    assert_eq!(
+        error("type", "foo", false),
+        "`type` is not a valid constant name"
+    );
+    assert_eq!(error("_", "foo", false), "`_` is not a valid constant name");
+    assert_eq!(
+        error("NUL", "foo\0", true),
+        "C-style string `NUL` cannot contain nul characters"
+    );
+
+    // Nul characters are allowed in strings that are not nul-terminated.
+    assert!(
+        WideStrings::new()
+            .add("NUL", "foo\0", WCharType::U16, false)
+            .generate()
+            .is_ok()
+    );
+
+    let duplicate = WideStrings::new()
+        .add("FOO", "foo", WCharType::U16, false)
+        .add("FOO", "bar", WCharType::U32, false)
+        .generate()
+        .unwrap_err();
+    assert_eq!(duplicate.to_string(), "duplicate constant name `FOO`");
+}
+
+#[test]
+fn write_to() {
+    let dir = std::env::temp_dir().join(format!("wchar-build-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("strings.rs");
+
+    let mut strings = WideStrings::new();
+    strings.add("FOO", "foo", WCharType::U16, false);
+    strings.write_to(&path).unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        strings.generate().unwrap()
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
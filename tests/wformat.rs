@@ -0,0 +1,74 @@
+#![cfg(feature = "alloc")]
+
+use std::fmt::Write;
+
+use wchar::{WString, WVecWriter, wch, wchar_t, wchz, wformat, wformatz};
+
+#[test]
+fn wformat() {
+    let name = "world";
+
+    assert_eq!(
+        wformat!(u16, "Hello, {}!", name),
+        wch!(u16, "Hello, world!")
+    );
+    assert_eq!(
+        wformat!(u32, "Hello, {name}!", name = name),
+        wch!(u32, "Hello, world!")
+    );
+    assert_eq!(wformat!(i16, "{:?}", '🦀'), wch!(i16, "'🦀'"));
+    assert_eq!(wformat!(i32, "{:05.1}", 1.25), wch!(i32, "001.2"));
+
+    let v: WString<wchar_t> = wformat!("{}{}", 4, 2);
+    assert_eq!(v, wch!("42"));
+
+    assert_eq!(wformat!(u16, ""), wch!(u16, ""));
+}
+
+#[test]
+fn wformatz() {
+    assert_eq!(wformatz!(u16, "{}-{}", "foo", 1), wchz!(u16, "foo-1"));
+    assert_eq!(wformatz!(i32, "{}", "🦀"), wchz!(i32, "🦀"));
+
+    let v: WString<wchar_t> = wformatz!("{}", "foo");
+    assert_eq!(v, wchz!("foo"));
+
+    assert_eq!(wformatz!(u32, ""), wchz!(u32, ""));
+}
+
+#[test]
+#[should_panic(expected = "formatted C-style string cannot contain nul characters")]
+fn wformatz_nul() {
+    let nul = "foo\0bar";
+    let _: WString<u16> = wformatz!(u16, "{}", nul);
+}
+
+#[test]
+fn vec_writer() {
+    let mut v = wch!(u16, "foo").to_vec();
+    let bar = "bar";
+    write!(WVecWriter::new(&mut v), " {}", bar).unwrap();
+
+    assert_eq!(v, wch!(u16, "foo bar"));
+}
+
+#[test]
+fn string() {
+    let mut s = WString::<u16>::new();
+    assert!(s.is_empty());
+
+    let crab = '🦀';
+    s.push_str("foo");
+    write!(s, " {}", crab).unwrap();
+    assert_eq!(s, wch!(u16, "foo 🦀"));
+    assert_eq!(s.len(), 6);
+    assert_eq!(s.as_slice(), wch!(u16, "foo 🦀"));
+
+    let v: Vec<u16> = s.clone().into();
+    assert_eq!(WString::from(v.clone()), s);
+    assert_eq!(s.into_vec(), v);
+
+    let z = wformatz!(i32, "{}", 1);
+    assert_eq!(z.last(), Some(&0));
+    assert_eq!(format!("{:?}", z), "[49, 0]");
+}
@@ -0,0 +1,83 @@
+use wchar::{wch, wchar_t, wchz, wformat_const, wformatz_const};
+
+macro_rules! test_wformat_const {
+    ($expected:literal, $($args:tt)*) => {{
+        assert_eq!(wformat_const!(u16, $($args)*), wch!(u16, $expected));
+        assert_eq!(wformat_const!(u32, $($args)*), wch!(u32, $expected));
+        assert_eq!(wformat_const!(i16, $($args)*), wch!(i16, $expected));
+        assert_eq!(wformat_const!(i32, $($args)*), wch!(i32, $expected));
+
+        assert_eq!(format!($($args)*), $expected);
+    }};
+}
+
+// Check we can use the macro to declare constants.
+const _: &[wchar_t] = wformat_const!("{}", "const");
+const _: &[u16] = wformat_const!(u16, "{}", "const");
+const _: &[u32] = wformatz_const!(u32, "{}", "const");
+const _: &[wchar_t] = wformatz_const!("{}", "const");
+
+#[test]
+fn literals() {
+    test_wformat_const!("foo", "foo");
+    test_wformat_const!("foo bar", "{} {}", "foo", "bar");
+    test_wformat_const!("42", "{}", 42);
+    test_wformat_const!("-42", "{}", -42);
+    test_wformat_const!("c", "{}", 'c');
+    test_wformat_const!("true", "{}", true);
+    test_wformat_const!("🦀", "{}", '🦀');
+    test_wformat_const!("{}", "{{}}");
+}
+
+#[test]
+fn arguments() {
+    test_wformat_const!("bar foo", "{1} {0}", "foo", "bar");
+    test_wformat_const!("foo foo", "{0} {0}", "foo");
+    test_wformat_const!("foo bar", "{a} {b}", a = "foo", b = "bar");
+    test_wformat_const!("1 foo 2", "{} {a} {}", 1, 2, a = "foo");
+    test_wformat_const!("foo", "{}", "foo",);
+}
+
+#[test]
+fn specs() {
+    test_wformat_const!("foo  ", "{:5}", "foo");
+    test_wformat_const!("  foo", "{:>5}", "foo");
+    test_wformat_const!(" foo ", "{:^5}", "foo");
+    test_wformat_const!("-foo--", "{:-^6}", "foo");
+    test_wformat_const!("   42", "{:5}", 42);
+    test_wformat_const!("42   ", "{:<5}", 42);
+    test_wformat_const!("00042", "{:05}", 42);
+    test_wformat_const!("-0042", "{:05}", -42);
+    test_wformat_const!("+42", "{:+}", 42);
+    test_wformat_const!("fo", "{:.2}", "foo");
+    test_wformat_const!("fo  ", "{:4.2}", "foo");
+    test_wformat_const!("ff", "{:x}", 255);
+    test_wformat_const!("FF", "{:X}", 255);
+    test_wformat_const!("0xff", "{:#x}", 255);
+    test_wformat_const!("0x00ff", "{:#06x}", 255);
+    test_wformat_const!("377", "{:o}", 255);
+    test_wformat_const!("0b101", "{:#b}", 5);
+    test_wformat_const!("\"foo\\n\"", "{:?}", "foo\n");
+    test_wformat_const!("'\\''", "{:?}", '\'');
+}
+
+#[test]
+fn env() {
+    let v = wformat_const!(
+        u16,
+        "{} v{}",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION")
+    );
+
+    assert_eq!(
+        String::from_utf16(v).unwrap(),
+        concat!("wchar v", env!("CARGO_PKG_VERSION"))
+    );
+}
+
+#[test]
+fn nul_terminated() {
+    assert_eq!(wformatz_const!(u16, "{}-{}", "foo", 1), wchz!(u16, "foo-1"));
+    assert_eq!(wformatz_const!(i32, "{:>4}", '🦀'), wchz!(i32, "   🦀"));
+}
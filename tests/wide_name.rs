@@ -0,0 +1,107 @@
+use wchar::{WideName, wch, wchz};
+
+#[derive(Debug, PartialEq, WideName)]
+#[wide(u16)]
+enum Event {
+    Start,
+    Stop,
+    #[wide(rename = "Process/Crash")]
+    Crash,
+    #[wide(rename = "🦀")]
+    Crab,
+}
+
+#[derive(Debug, PartialEq, WideName)]
+#[wide(u32, nul)]
+enum ClassName {
+    Button,
+    #[wide(rename = "Edit")]
+    TextBox,
+}
+
+#[derive(Debug, PartialEq, WideName)]
+#[wide(i16)]
+enum Signed {
+    #[wide(rename = "京")]
+    Capital,
+    #[wide(rename = "𐐷")]
+    Deseret,
+}
+
+#[derive(Debug, PartialEq, WideName)]
+#[wide(i32, nul)]
+enum Single {
+    Only,
+}
+
+#[derive(Debug, PartialEq, WideName)]
+#[wide(u16)]
+enum Empty {}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, WideName)]
+#[wide(u16)]
+enum Keyword {
+    r#type,
+    r#match,
+}
+
+#[test]
+fn wide_name() {
+    assert_eq!(Event::Start.wide_name(), wch!(u16, "Start"));
+    assert_eq!(Event::Stop.wide_name(), wch!(u16, "Stop"));
+    assert_eq!(Event::Crash.wide_name(), wch!(u16, "Process/Crash"));
+    assert_eq!(Event::Crab.wide_name(), wch!(u16, "🦀"));
+
+    assert_eq!(ClassName::Button.wide_name(), wchz!(u32, "Button"));
+    assert_eq!(ClassName::TextBox.wide_name(), wchz!(u32, "Edit"));
+
+    assert_eq!(Signed::Capital.wide_name(), wch!(i16, "京"));
+    assert_eq!(Signed::Deseret.wide_name(), wch!(i16, "𐐷"));
+
+    assert_eq!(Single::Only.wide_name(), wchz!(i32, "Only"));
+
+    assert_eq!(Keyword::r#type.wide_name(), wch!(u16, "type"));
+    assert_eq!(Keyword::r#match.wide_name(), wch!(u16, "match"));
+}
+
+#[test]
+fn from_wide() {
+    assert_eq!(Event::from_wide(wch!(u16, "Start")), Some(Event::Start));
+    assert_eq!(Event::from_wide(wch!(u16, "Stop")), Some(Event::Stop));
+    assert_eq!(
+        Event::from_wide(wch!(u16, "Process/Crash")),
+        Some(Event::Crash)
+    );
+    assert_eq!(Event::from_wide(wch!(u16, "🦀")), Some(Event::Crab));
+    assert_eq!(Event::from_wide(wch!(u16, "Crash")), None);
+    assert_eq!(Event::from_wide(wch!(u16, "start")), None);
+    assert_eq!(Event::from_wide(&[]), None);
+
+    assert_eq!(
+        ClassName::from_wide(wchz!(u32, "Button")),
+        Some(ClassName::Button)
+    );
+    assert_eq!(
+        ClassName::from_wide(wchz!(u32, "Edit")),
+        Some(ClassName::TextBox)
+    );
+    assert_eq!(ClassName::from_wide(wch!(u32, "Edit")), None);
+
+    assert_eq!(Signed::from_wide(wch!(i16, "京")), Some(Signed::Capital));
+    assert_eq!(Signed::from_wide(wch!(i16, "𐐷")), Some(Signed::Deseret));
+
+    assert_eq!(Single::from_wide(wchz!(i32, "Only")), Some(Single::Only));
+
+    assert_eq!(Empty::from_wide(wch!(u16, "Empty")), None);
+
+    assert_eq!(Keyword::from_wide(wch!(u16, "type")), Some(Keyword::r#type));
+    assert_eq!(Keyword::from_wide(wch!(u16, "r#type")), None);
+}
+
+#[test]
+fn round_trip() {
+    for event in [Event::Start, Event::Stop, Event::Crash, Event::Crab] {
+        assert_eq!(Event::from_wide(event.wide_name()), Some(event));
+    }
+}
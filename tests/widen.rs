@@ -0,0 +1,107 @@
+use wchar::{wch, wchz};
+
+#[wchar::widen(u16)]
+mod utf16 {
+    #[wide]
+    pub const FOO: &str = "foo";
+    #[wide(nul)]
+    pub const FOO_C: &str = "foo";
+    #[wide]
+    pub static BAR: &str = "bar";
+    #[wide]
+    pub const CRAB: &str = "🦀";
+    #[wide]
+    pub const CHAR: char = 'x';
+
+    pub const NARROW: &str = "narrow";
+
+    pub struct Window;
+
+    impl Window {
+        #[wide(nul)]
+        pub const CLASS_NAME: &'static str = "Window";
+    }
+
+    pub fn local() -> &'static [u16] {
+        #[wide]
+        let s: &'static str = "local";
+        s
+    }
+
+    pub fn local_untyped() -> &'static [u16] {
+        #[wide(nul)]
+        let s = "local";
+        s
+    }
+
+    pub fn format(name: &str) -> String {
+        let greeting = "Hello";
+        format!("{}, {}!", greeting, name)
+    }
+
+    pub mod nested {
+        #[wide]
+        pub const NESTED: &str = "nested";
+    }
+}
+
+#[wchar::widen(u32)]
+fn utf32() -> (&'static [u32], &'static [u32], u32) {
+    #[wide]
+    const FOO: &str = "foo";
+    #[wide(nul)]
+    let bar: &str = "bar";
+    #[wide]
+    let c: char = '🦀';
+
+    (FOO, bar, c)
+}
+
+#[wchar::widen(i16)]
+mod utf16_signed {
+    #[wide]
+    pub const CRAB: &str = "🦀";
+}
+
+#[wchar::widen(i32)]
+mod utf32_signed {
+    #[wide(nul)]
+    pub const CRAB: &str = "🦀";
+}
+
+#[wchar::widen(u16)]
+#[wide]
+const TOP_LEVEL: &str = "top level";
+
+#[test]
+fn consts() {
+    assert_eq!(utf16::FOO, wch!(u16, "foo"));
+    assert_eq!(utf16::FOO_C, wchz!(u16, "foo"));
+    assert_eq!(utf16::BAR, wch!(u16, "bar"));
+    assert_eq!(utf16::CRAB, wch!(u16, "🦀"));
+    assert_eq!(utf16::CHAR, wch!(u16, 'x'));
+    assert_eq!(utf16::Window::CLASS_NAME, wchz!(u16, "Window"));
+    assert_eq!(utf16::nested::NESTED, wch!(u16, "nested"));
+
+    assert_eq!(utf16_signed::CRAB, wch!(i16, "🦀"));
+    assert_eq!(utf32_signed::CRAB, wchz!(i32, "🦀"));
+
+    assert_eq!(TOP_LEVEL, wch!(u16, "top level"));
+}
+
+#[test]
+fn locals() {
+    assert_eq!(utf16::local(), wch!(u16, "local"));
+    assert_eq!(utf16::local_untyped(), wchz!(u16, "local"));
+
+    let (foo, bar, c) = utf32();
+    assert_eq!(foo, wch!(u32, "foo"));
+    assert_eq!(bar, wchz!(u32, "bar"));
+    assert_eq!(c, wch!(u32, '🦀'));
+}
+
+#[test]
+fn unmarked() {
+    assert_eq!(utf16::NARROW, "narrow");
+    assert_eq!(utf16::format("Rust"), "Hello, Rust!");
+}
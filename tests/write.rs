@@ -0,0 +1,108 @@
+use std::fmt::Write;
+
+use wchar::{WSliceWriter, WideChar, wch, wchz};
+
+mod util;
+
+macro_rules! test_write {
+    ($s:literal) => {{
+        use util::Wide;
+
+        let string = $s;
+
+        let mut buf = [0u16; 64];
+        let mut w = WSliceWriter::new(&mut buf);
+        write!(w, "{}", string).unwrap();
+        assert_eq!(w.as_slice(), &*u16::encode_str(string));
+
+        let mut buf = [0u32; 64];
+        let mut w = WSliceWriter::new(&mut buf);
+        write!(w, "{}", string).unwrap();
+        assert_eq!(w.as_slice(), &*u32::encode_str(string));
+
+        let mut buf = [0i16; 64];
+        let mut w = WSliceWriter::new(&mut buf);
+        write!(w, "{}", string).unwrap();
+        assert_eq!(w.as_slice(), &*i16::encode_str(string));
+
+        let mut buf = [0i32; 64];
+        let mut w = WSliceWriter::new(&mut buf);
+        write!(w, "{}", string).unwrap();
+        assert_eq!(w.as_slice(), &*i32::encode_str(string));
+    }};
+}
+
+#[test]
+fn basic() {
+    test_write!("foo");
+    test_write!("foo bar");
+    test_write!("");
+}
+
+#[test]
+fn complex() {
+    test_write!("京");
+    test_write!("𐐷");
+    test_write!("🦀");
+    test_write!("🇬🇧");
+}
+
+#[test]
+fn format() {
+    let mut buf = [0u16; 32];
+    let mut w = WSliceWriter::new(&mut buf);
+
+    write!(w, "{:>4}|{:<4}|{:x}", 1, 'a', 255).unwrap();
+    assert_eq!(w.as_slice(), wch!(u16, "   1|a   |ff"));
+    assert_eq!(w.len(), 12);
+    assert_eq!(w.capacity(), 32);
+    assert!(!w.is_empty());
+
+    w.write_nul().unwrap();
+    assert_eq!(w.into_slice(), wchz!(u16, "   1|a   |ff"));
+}
+
+#[test]
+fn overflow() {
+    let mut buf = [0u16; 4];
+    let mut w = WSliceWriter::new(&mut buf);
+
+    assert!(write!(w, "foobar").is_err());
+    assert_eq!(w.as_slice(), wch!(u16, "foob"));
+    assert!(w.write_nul().is_err());
+
+    // Characters are never split across the end of the buffer.
+    let mut buf = [0u16; 3];
+    let mut w = WSliceWriter::new(&mut buf);
+
+    assert!(write!(w, "a🦀🦀").is_err());
+    assert_eq!(w.as_slice(), wch!(u16, "a🦀"));
+
+    let mut buf = [0u32; 0];
+    let mut w = WSliceWriter::new(&mut buf);
+
+    assert!(write!(w, "").is_ok());
+    assert!(w.is_empty());
+    assert!(write!(w, "a").is_err());
+}
+
+#[test]
+fn wide_char() {
+    assert_eq!(u16::char_len('a'), 1);
+    assert_eq!(u16::char_len('🦀'), 2);
+    assert_eq!(i16::char_len('🦀'), 2);
+    assert_eq!(u32::char_len('🦀'), 1);
+    assert_eq!(i32::char_len('🦀'), 1);
+
+    let mut buf = [0; 2];
+    assert_eq!(u16::encode_char('🦀', &mut buf), wch!(u16, "🦀"));
+    let mut buf = [0; 2];
+    assert_eq!(i16::encode_char('🦀', &mut buf), wch!(i16, "🦀"));
+    let mut buf = [0; 1];
+    assert_eq!(u32::encode_char('🦀', &mut buf), wch!(u32, "🦀"));
+    let mut buf = [0; 1];
+    assert_eq!(i32::encode_char('京', &mut buf), wch!(i32, "京"));
+
+    assert_eq!(u16::NUL, 0);
+    assert_eq!(i32::NUL, 0);
+}